@@ -0,0 +1,52 @@
+//! Compares sampling from a precomputed [`WeightedTable`] against sampling the same
+//! weights with [`DelegatedRng::weighted_index`], which walks the weights for every sample.
+//!
+//! Run with `cargo bench --bench weighted_table`.
+
+use bevy_turborand::prelude::*;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SAMPLES: usize = 100_000;
+
+fn time(name: &str, len: usize, mut sample: impl FnMut() -> usize) -> Duration {
+    let start = Instant::now();
+    let mut total = 0;
+
+    for _ in 0..SAMPLES {
+        total += sample();
+    }
+
+    let elapsed = start.elapsed();
+
+    black_box(total);
+    println!(
+        "{:>16} over {:>5} items: {:>8.2} ns/sample",
+        name,
+        len,
+        elapsed.as_nanos() as f64 / SAMPLES as f64
+    );
+
+    elapsed
+}
+
+fn main() {
+    for len in [8, 64, 512, 4096] {
+        let weights: Vec<f64> = (1..=len).map(|weight| weight as f64).collect();
+        let table = WeightedTable::new((0..len).collect(), &weights).unwrap();
+
+        let mut rng = RngComponent::with_seed(12345);
+        let naive = time("weighted_index", len, || {
+            rng.weighted_index(black_box(&weights)).unwrap()
+        });
+
+        let mut rng = RngComponent::with_seed(12345);
+        let alias = time("WeightedTable", len, || *table.sample(&mut rng));
+
+        println!(
+            "{:>16} {:.1}x",
+            "speedup:",
+            naive.as_secs_f64() / alias.as_secs_f64()
+        );
+    }
+}
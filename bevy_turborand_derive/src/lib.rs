@@ -0,0 +1,64 @@
+//! Derive macros for [`bevy_turborand`](https://docs.rs/bevy_turborand). Not meant to be
+//! used directly, but through the re-exports enabled by `bevy_turborand`'s `derive` feature.
+#![warn(missing_docs, rust_2018_idioms)]
+
+use proc_macro::TokenStream;
+use proc_macro2::Literal;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Derives `RandomVariant` for an enum, selecting uniformly among its unit variants.
+/// Variants with fields are never selected, and deriving it for enums without any unit
+/// variants, or for anything other than an enum, is a compile error.
+#[proc_macro_derive(RandomVariant)]
+pub fn derive_random_variant(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_random_variant(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_random_variant(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "RandomVariant can only be derived for enums",
+            ))
+        }
+    };
+
+    let variants: Vec<_> = data
+        .variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, Fields::Unit))
+        .map(|variant| &variant.ident)
+        .collect();
+
+    let (last, rest) = variants.split_last().ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "RandomVariant requires the enum to have at least one unit variant",
+        )
+    })?;
+
+    let name = &input.ident;
+    let count = Literal::u64_unsuffixed(variants.len() as u64);
+    let indices = (0..rest.len() as u64).map(Literal::u64_unsuffixed);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The index is drawn as a `u64` so the selection is the same on all platforms
+    Ok(quote! {
+        impl #impl_generics ::bevy_turborand::RandomVariant for #name #ty_generics #where_clause {
+            #[inline]
+            fn random(rng: &mut impl ::bevy_turborand::DelegatedRng) -> Self {
+                match ::bevy_turborand::DelegatedRng::u64(rng, ..#count) {
+                    #(#indices => Self::#rest,)*
+                    _ => Self::#last,
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,43 @@
+use crate::*;
+use bevy::ecs::schedule::StateData;
+
+/// An extension trait for [`App`], for declaratively tying the [`GlobalRng`] to the
+/// app's states.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     Menu,
+///     Dungeon,
+/// }
+///
+/// App::new()
+///     .add_plugin(RngPlugin::new())
+///     .add_state(GameState::Menu)
+///     .reseed_on_enter(GameState::Dungeon, 12345);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub trait RngAppExt {
+    /// Reseeds the [`GlobalRng`] with the given seed every time `state` is entered, so
+    /// that anything generated on entering the state, such as a dungeon layout, is the same
+    /// each time. Uses [`SystemSet::on_enter`], so the state must be registered with
+    /// [`App::add_state`]. The reseeding system is labelled with [`RngSystem::Reseed`], so
+    /// other `on_enter` systems for the same state can be ordered after it.
+    fn reseed_on_enter<S: StateData>(&mut self, state: S, seed: u64) -> &mut Self;
+}
+
+impl RngAppExt for App {
+    fn reseed_on_enter<S: StateData>(&mut self, state: S, seed: u64) -> &mut Self {
+        self.add_system_set(
+            SystemSet::on_enter(state)
+                .label(RngSystem::Reseed)
+                .with_system(move |mut global: ResMut<'_, GlobalRng>| {
+                    global.reseed(seed);
+                }),
+        )
+    }
+}
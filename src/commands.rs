@@ -0,0 +1,118 @@
+use crate::*;
+use bevy::ecs::system::EntityCommands;
+
+/// An extension trait for [`Commands`], for spawning entities with an [`RngComponent`]
+/// attached in one call.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Component)]
+/// struct Enemy;
+///
+/// fn spawn_enemies(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+///     for _ in 0..5 {
+///         commands
+///             .spawn_with_rng(&mut global, Enemy)
+///             .insert(Name::new("Enemy"));
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(spawn_enemies);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub trait SpawnRandomExt<'w, 's> {
+    /// Spawns a new entity with the given [`Bundle`], plus an [`RngComponent`] seeded
+    /// from the given source, such as a [`GlobalRng`] or another [`RngComponent`]. Returns
+    /// the [`EntityCommands`] of the new entity for further chaining.
+    fn spawn_with_rng<'a, R, B>(&'a mut self, rng: R, bundle: B) -> EntityCommands<'w, 's, 'a>
+    where
+        R: Into<RngComponent>,
+        B: Bundle;
+}
+
+impl<'w, 's> SpawnRandomExt<'w, 's> for Commands<'w, 's> {
+    #[inline]
+    fn spawn_with_rng<'a, R, B>(&'a mut self, rng: R, bundle: B) -> EntityCommands<'w, 's, 'a>
+    where
+        R: Into<RngComponent>,
+        B: Bundle,
+    {
+        self.spawn((bundle, rng.into()))
+    }
+}
+
+/// An extension trait for [`Commands`], for attaching an [`RngComponent`] seeded from the
+/// [`GlobalRng`] without needing to access the [`GlobalRng`] in the system itself. The
+/// seed is drawn from the [`GlobalRng`] when the command is applied, so systems queueing
+/// these commands don't conflict with other systems accessing the [`GlobalRng`].
+///
+/// Commands are applied in the order they were queued, so with a seeded [`GlobalRng`],
+/// the same sequence of commands always produces the same [`RngComponent`]s.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Component)]
+/// struct Enemy;
+///
+/// fn spawn_enemies(mut commands: Commands) {
+///     for _ in 0..5 {
+///         commands.spawn_with_global_rng(Enemy);
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(spawn_enemies);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub trait RngCommandsExt<'w, 's> {
+    /// Queues attaching an [`RngComponent`] to the given entity, seeded from the
+    /// [`GlobalRng`] when the command is applied. If the entity no longer exists by then,
+    /// the command does nothing and the [`GlobalRng`] is not advanced.
+    ///
+    /// # Panics
+    ///
+    /// The command panics when applied if there is no [`GlobalRng`] resource.
+    fn attach_rng(&mut self, entity: Entity);
+
+    /// Spawns a new entity with the given [`Bundle`], and queues attaching an
+    /// [`RngComponent`] seeded from the [`GlobalRng`] to it, as with
+    /// [`RngCommandsExt::attach_rng`]. Returns the [`EntityCommands`] of the new entity for
+    /// further chaining.
+    fn spawn_with_global_rng<'a, B: Bundle>(&'a mut self, bundle: B) -> EntityCommands<'w, 's, 'a>;
+}
+
+impl<'w, 's> RngCommandsExt<'w, 's> for Commands<'w, 's> {
+    #[inline]
+    fn attach_rng(&mut self, entity: Entity) {
+        self.add(AttachRng { entity });
+    }
+
+    #[inline]
+    fn spawn_with_global_rng<'a, B: Bundle>(&'a mut self, bundle: B) -> EntityCommands<'w, 's, 'a> {
+        let entity = self.spawn(bundle).id();
+
+        self.attach_rng(entity);
+        self.entity(entity)
+    }
+}
+
+/// A [`Command`](bevy::ecs::system::Command) that attaches an [`RngComponent`] seeded from
+/// the [`GlobalRng`] to an entity.
+struct AttachRng {
+    entity: Entity,
+}
+
+impl bevy::ecs::system::Command for AttachRng {
+    fn write(self, world: &mut World) {
+        if world.get_entity(self.entity).is_none() {
+            return;
+        }
+
+        let rng = RngComponent::from(&mut world.resource_mut::<GlobalRng>());
+
+        world.entity_mut(self.entity).insert(rng);
+    }
+}
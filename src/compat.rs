@@ -0,0 +1,70 @@
+use crate::*;
+use rand_core::{CryptoRng, RngCore};
+
+/// A compatibility shim over a borrowed [`ChaChaRng`], implementing both [`RngCore`] and
+/// [`CryptoRng`] so that it can be used with crates from the `rand` ecosystem that
+/// require a cryptographically secure source.
+///
+/// Unlike [`RandBorrowed`], which can wrap any [`TurboCore`] source, [`SecureRandBorrowed`]
+/// can only be created from a [`ChaChaRng`], as it is the only source provided that is
+/// suitable for use as a [`CryptoRng`]. The WyRand based [`Rng`](turborand::prelude::Rng)
+/// must never be used where a [`CryptoRng`] is expected.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+/// use rand_core::{CryptoRng, RngCore};
+///
+/// fn generate_key<R: RngCore + CryptoRng>(rng: &mut R) -> [u8; 32] {
+///     let mut key = [0; 32];
+///     rng.fill_bytes(&mut key);
+///     key
+/// }
+///
+/// fn create_session_key(mut global: ResMut<GlobalChaChaRng>) {
+///     let key = generate_key(&mut global.as_secure_rand());
+///
+///     println!("Generated key: {:?}", key);
+/// }
+/// # bevy::ecs::system::assert_is_system(create_session_key);
+/// ```
+#[derive(PartialEq, Eq)]
+#[repr(transparent)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "rand", feature = "chacha"))))]
+pub struct SecureRandBorrowed<'a>(&'a mut ChaChaRng);
+
+impl<'a> From<&'a mut ChaChaRng> for SecureRandBorrowed<'a> {
+    /// Convert a [`ChaChaRng`] reference into a [`SecureRandBorrowed`] struct,
+    /// allowing a borrowed reference to be used with the `rand` crate
+    /// ecosystem as a [`CryptoRng`].
+    #[inline]
+    fn from(rng: &'a mut ChaChaRng) -> Self {
+        Self(rng)
+    }
+}
+
+impl RngCore for SecureRandBorrowed<'_> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.0.gen_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0.gen_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest);
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for SecureRandBorrowed<'_> {}
@@ -58,6 +58,15 @@ use crate::*;
 #[derive(Debug, Clone, Component)]
 #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect, FromReflect))]
+#[cfg_attr(
+    all(feature = "bevy_reflect", not(feature = "serialize")),
+    reflect_value(Component, Default)
+)]
+#[cfg_attr(
+    all(feature = "bevy_reflect", feature = "serialize"),
+    reflect_value(Component, Default, Serialize, Deserialize)
+)]
 pub struct ChaChaRngComponent(ChaChaRng);
 
 unsafe impl Sync for ChaChaRngComponent {}
@@ -76,27 +85,50 @@ impl ChaChaRngComponent {
     pub fn with_seed(seed: [u8; 40]) -> Self {
         Self(ChaChaRng::with_seed(seed))
     }
+
+    /// Create a new [`ChaChaRngComponent`] instance from an existing [`ChaChaRng`], taking ownership of it
+    /// and preserving its exact internal state, so the [`ChaChaRngComponent`] continues the same sequence.
+    #[inline]
+    #[must_use]
+    pub fn from_rng(rng: ChaChaRng) -> Self {
+        Self(rng)
+    }
+
+    /// Generates `N` cryptographically secure random bytes from the ChaCha8 core in one
+    /// call, such as for session tokens or nonces.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = ChaChaRngComponent::new();
+    ///
+    /// let token: [u8; 32] = rng.random_bytes();
+    ///
+    /// assert_eq!(token.len(), 32);
+    /// ```
+    #[inline]
+    pub fn random_bytes<const N: usize>(&mut self) -> [u8; N] {
+        self.0.gen()
+    }
+
+    /// Return a compatibility shim for working with crates from the `rand`
+    /// ecosystem that require a [`CryptoRng`](rand_core::CryptoRng) source.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[inline]
+    pub fn as_secure_rand(&mut self) -> SecureRandBorrowed<'_> {
+        SecureRandBorrowed::from(&mut self.0)
+    }
 }
 
 impl DelegatedRng for ChaChaRngComponent {
     type Source = ChaChaRng;
 
     #[inline]
-    #[must_use]
     fn get_mut(&mut self) -> &mut Self::Source {
         &mut self.0
     }
-
-    #[inline]
-    fn weighted_sample_mut<'a, T, F>(
-        &'a mut self,
-        list: &'a mut [T],
-        weight_sampler: F,
-    ) -> Option<&'a mut T>
-    where
-        F: Fn(&T) -> f64 {
-        self.0.weighted_sample_mut(list, weight_sampler)
-    }
 }
 
 impl Default for ChaChaRngComponent {
@@ -111,7 +143,6 @@ impl Default for ChaChaRngComponent {
 
 impl<T: TurboCore + GenCore + SecureCore> From<&T> for ChaChaRngComponent {
     #[inline]
-    #[must_use]
     fn from(rng: &T) -> Self {
         Self(ChaChaRng::with_seed(rng.gen()))
     }
@@ -122,7 +153,6 @@ where
     T::Source: SecureCore,
 {
     #[inline]
-    #[must_use]
     fn from(rng: &mut T) -> Self {
         Self(ChaChaRng::with_seed(rng.get_mut().gen()))
     }
@@ -133,7 +163,6 @@ where
     T::Source: SecureCore,
 {
     #[inline]
-    #[must_use]
     fn from(rng: &mut Mut<'_, T>) -> Self {
         Self(ChaChaRng::with_seed(rng.get_mut().gen()))
     }
@@ -145,7 +174,6 @@ where
     T::Source: SecureCore,
 {
     #[inline]
-    #[must_use]
     fn from(rng: &mut ResMut<'_, T>) -> Self {
         Self(ChaChaRng::with_seed(rng.get_mut().gen()))
     }
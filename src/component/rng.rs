@@ -1,4 +1,5 @@
 use crate::*;
+use alloc::vec::Vec;
 
 /// A [`Rng`] component that wraps a random number generator,
 /// specifically the [`Rng`] struct, which provides a fast, but
@@ -10,6 +11,25 @@ use crate::*;
 /// a mut reference to a [`DelegatedRng`], such as [`ResMut`] or a
 /// [`Component`], or from a [`TurboCore`] source directly.
 ///
+/// # Cloning
+///
+/// Cloning an [`RngComponent`] does **not** produce an identical copy. Instead, the clone
+/// is seeded from a single draw of the original, the same as [`RngComponent::from`], so
+/// that the two diverge deterministically. This makes `clone` safe for spawning child
+/// entities with independent RNGs, but note that it advances the state of the original.
+/// For a true copy with identical internal state, use [`RngComponent::clone_exact`].
+/// Reflection, such as `Reflect::clone_value` and applying reflected values, always
+/// preserves the exact state. Applying reflected data onto an existing [`RngComponent`],
+/// such as when reloading a scene, overwrites its internal state with the reflected one,
+/// while components that are absent from the reflected data keep their state.
+///
+/// # Equality
+///
+/// [`RngComponent`]s compare equal when their internal states are identical, meaning they
+/// will go on to generate the same sequence of values. With the `serialize` feature
+/// enabled, they also implement [`Hash`](core::hash::Hash) based on the same state, so
+/// they can be used as keys for deduplicating RNG states.
+///
 /// # Examples
 ///
 /// From a resource:
@@ -54,26 +74,374 @@ use crate::*;
 ///    }
 /// }
 /// ```
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Component)]
 #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
-#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-pub struct RngComponent(Rng);
+pub struct RngComponent(pub(crate) Rng, crate::state::DrawStats);
 
 unsafe impl Sync for RngComponent {}
 
 impl RngComponent {
+    /// The number of bytes a [`RngComponent`] takes up when serialized with a compact binary
+    /// format using fixed-width integers, such as `bincode`'s default configuration. This is
+    /// the 8 bytes of the internal WyRand state, and is stable for a given `turborand` version.
+    /// Self-describing formats such as JSON take up more space, and varint encodings may
+    /// take up less or more depending on the state.
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    pub const SERIALIZED_SIZE: usize = core::mem::size_of::<u64>();
+
     /// Create a new [`RngComponent`] with a randomised seed.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(Rng::new())
+        Self::from_rng(Rng::new())
     }
 
     /// Create a new [`RngComponent`] instance with a given seed.
     #[inline]
     #[must_use]
     pub fn with_seed(seed: u64) -> Self {
-        Self(Rng::with_seed(seed))
+        Self::from_rng(Rng::with_seed(seed))
+    }
+
+    /// Create a new [`RngComponent`] instance from an existing [`Rng`], taking ownership of it
+    /// and preserving its exact internal state, so the [`RngComponent`] continues the same sequence.
+    #[inline]
+    #[must_use]
+    pub fn from_rng(rng: Rng) -> Self {
+        let stats = crate::state::DrawStats::new(&rng);
+
+        Self(rng, stats)
+    }
+
+    /// Create a new [`RngComponent`] instance with a seed given as bytes, interpreted in
+    /// little-endian order. Equivalent to calling [`RngComponent::with_seed`] with
+    /// [`u64::from_le_bytes`], which is useful for seeds derived from hashes or network data.
+    #[inline]
+    #[must_use]
+    pub fn with_seed_bytes(bytes: [u8; 8]) -> Self {
+        Self::with_seed(u64::from_le_bytes(bytes))
+    }
+
+    /// Create a new [`RngComponent`] with a seed derived from an [`Entity`] and a
+    /// base seed. Both the index and generation of the [`Entity`] are hashed together
+    /// with the base seed, so the same [`Entity`] will always yield the same sequence
+    /// for a given base seed, regardless of the order in which entities were spawned.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn setup_rng(mut commands: Commands, q_entities: Query<Entity, Without<RngComponent>>) {
+    ///     for entity in q_entities.iter() {
+    ///         commands
+    ///             .entity(entity)
+    ///             .insert(RngComponent::from_entity(entity, 12345));
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_entity(entity: Entity, base_seed: u64) -> Self {
+        Self::with_seed(crate::hash::mix_seed(base_seed, entity.to_bits()))
+    }
+
+    /// Create a batch of `count` independent [`RngComponent`]s from a base seed, for bulk
+    /// spawning entities without drawing from a [`GlobalRng`] once per entity. Each
+    /// component is seeded with a counter-based SplitMix64 derivation of the base seed and
+    /// its index in the batch, so no seed depends on generating the others, and the same
+    /// base seed always yields the same batch.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// #[derive(Debug, Component)]
+    /// struct Enemy;
+    ///
+    /// fn spawn_horde(mut commands: Commands) {
+    ///     let batch = RngComponent::batch_from_seed(12345, 1000)
+    ///         .into_iter()
+    ///         .map(|rng| (Enemy, rng));
+    ///
+    ///     commands.spawn_batch(batch.collect::<Vec<_>>());
+    /// }
+    /// # bevy::ecs::system::assert_is_system(spawn_horde);
+    /// ```
+    #[must_use]
+    pub fn batch_from_seed(base_seed: u64, count: usize) -> Vec<Self> {
+        (0..count as u64)
+            .map(|index| Self::with_seed(crate::hash::nth_seed(base_seed, index)))
+            .collect()
+    }
+
+    /// Splits the [`RngComponent`] into `n` independent child [`RngComponent`]s, for
+    /// hierarchical procedural generation such as a region splitting into sub-regions.
+    /// Advances the parent exactly once, using the drawn value as the base seed of a
+    /// [`RngComponent::batch_from_seed`] batch, so the children only depend on the parent's
+    /// state and splitting from the same state always yields the same children.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut region = RngComponent::with_seed(12345);
+    ///
+    /// let sub_regions = region.split(4);
+    ///
+    /// assert_eq!(sub_regions.len(), 4);
+    /// ```
+    #[must_use]
+    pub fn split(&mut self, n: usize) -> Vec<Self> {
+        Self::batch_from_seed(self.0.gen_u64(), n)
+    }
+
+    /// Reseeds the [`RngComponent`] in place with a new seed, resetting its
+    /// internal state. Reseeding with the same seed will always yield the
+    /// same sequence of values afterwards, making it possible to re-establish
+    /// determinism mid-run without replacing the whole [`RngComponent`]. The sequence
+    /// is the same as that of a new [`RngComponent::with_seed`] with the same seed, so
+    /// reseeding restarts a level exactly as it played the first time.
+    #[inline]
+    pub fn reseed(&mut self, seed: u64) {
+        self.1.reseed(&mut self.0, seed);
+    }
+
+    /// Advances the internal state of the [`RngComponent`] by `n` draws, as if `n`
+    /// `u64` values had been generated and discarded. Useful for fast-forwarding
+    /// an RNG in order to resync with a known state, such as from a server.
+    ///
+    /// A draw here is a single step of the underlying WyRand generator. Most
+    /// delegated methods consume one step, though 128-bit values consume two and
+    /// range based methods may consume more in order to avoid bias.
+    ///
+    /// With the `serialize` feature enabled, this is a constant time jump, as
+    /// WyRand's state advances by a fixed increment for every draw. Otherwise,
+    /// the draws are generated and discarded, so the cost is linear in `n`.
+    #[inline]
+    pub fn skip(&mut self, n: u64) {
+        crate::state::skip(&self.0, n);
+    }
+
+    /// Returns the number of draws made from the [`RngComponent`] since it was created, for
+    /// profiling which systems are RNG heavy. A draw is a single `u64` sized step of the
+    /// underlying WyRand generator, as with [`RngComponent::skip`], so skipped draws are counted too.
+    /// This is not a count of method calls: most delegated methods take one step, but 128-bit
+    /// values take two and range based methods may take more in order to avoid bias.
+    ///
+    /// The count is derived from how far the internal state has advanced, so every draw is
+    /// counted, including ones made through [`DelegatedRng::get_mut`], without any overhead
+    /// per draw. Reseeding with [`RngComponent::reseed`] or [`DelegatedRng::reseed`] keeps the
+    /// count, while restoring a [`RngComponent`] from a saved state, such as by deserializing it,
+    /// starts counting from zero. Reseeding the internal [`Rng`] directly, such as with
+    /// `rng.get_mut().reseed(seed)`, makes the count meaningless.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// for _ in 0..3 {
+    ///     rng.u64(..);
+    /// }
+    ///
+    /// assert_eq!(rng.draws(), 3);
+    /// ```
+    #[cfg(feature = "rng_stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rng_stats")))]
+    #[inline]
+    #[must_use]
+    pub fn draws(&self) -> u64 {
+        self.1.draws(&self.0)
+    }
+
+    /// Resets the [`RngComponent`] to the state it would be in after being created with
+    /// [`RngComponent::with_seed`] and then drawn from `draw_count` times, for restoring an
+    /// exact position in a replay from a logged seed and [`RngComponent::draw_count`]. The
+    /// skip is a constant time jump with the `serialize` feature enabled, see
+    /// [`RngComponent::skip`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// rng.u64(..);
+    /// rng.u64(..);
+    ///
+    /// let mut replayed = RngComponent::new();
+    ///
+    /// replayed.advance_to(12345, 2);
+    ///
+    /// assert_eq!(replayed.u64(..), rng.u64(..));
+    /// ```
+    #[inline]
+    pub fn advance_to(&mut self, seed: u64, draw_count: u64) {
+        *self = Self::with_seed(seed);
+        self.skip(draw_count);
+    }
+
+    /// Returns the number of draws made since the [`RngComponent`] was created with
+    /// [`RngComponent::with_seed`] using the given `seed`, for logging alongside the seed and
+    /// restoring later with [`RngComponent::advance_to`]. The count is computed from the
+    /// internal state, so every draw is counted, including ones made through
+    /// [`DelegatedRng::get_mut`], without any tracking overhead.
+    ///
+    /// A draw is a single step of the underlying WyRand generator, as with
+    /// [`RngComponent::skip`]. If the [`RngComponent`] did not originate from `seed`, such as
+    /// after being reseeded, the returned count is meaningless.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// for _ in 0..5 {
+    ///     rng.u32(..);
+    /// }
+    ///
+    /// assert_eq!(rng.draw_count(12345), 5);
+    /// ```
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    #[must_use]
+    pub fn draw_count(&self, seed: u64) -> u64 {
+        let origin = crate::state::rng_state(&Self::with_seed(seed).0);
+
+        crate::state::draws_between(origin, crate::state::rng_state(&self.0))
+    }
+
+    /// Encodes the exact internal state of the [`RngComponent`] as 16 lowercase hex digits, for
+    /// dumping into logs and bug reports when diagnosing determinism issues. The state can
+    /// be restored with [`RngComponent::from_state_hex`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let hex = rng.state_hex();
+    ///
+    /// let mut restored = RngComponent::from_state_hex(&hex).unwrap();
+    ///
+    /// assert_eq!(restored.u64(..), rng.u64(..));
+    /// ```
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    #[must_use]
+    pub fn state_hex(&self) -> alloc::string::String {
+        crate::state::state_hex(&self.0)
+    }
+
+    /// Creates a new [`RngComponent`] instance from a hex string produced by
+    /// [`RngComponent::state_hex`], restoring the exact internal state.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateParseError`] if the string is not exactly 16 hex digits.
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    pub fn from_state_hex(hex: &str) -> Result<Self, StateParseError> {
+        crate::state::from_state_hex(hex).map(Self::from_rng)
+    }
+
+    /// Returns the raw internal state of the [`RngComponent`] as a single `u64`, which can be
+    /// restored with [`RngComponent::from_state`]. The WyRand state is exactly one `u64`, so this
+    /// is the most compact way of saving an RNG, at 8 bytes without any framing. Unlike
+    /// [`SerializableRngState`], the value carries no format version, so it relies on the
+    /// WyRand state layout staying the same.
+    ///
+    /// `turborand` only exposes the state through its `Serialize` implementation, which is
+    /// why this requires the `serialize` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let state = rng.state();
+    ///
+    /// let mut restored = RngComponent::from_state(state);
+    ///
+    /// assert_eq!(restored.u64(..), rng.u64(..));
+    /// ```
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    #[must_use]
+    pub fn state(&self) -> u64 {
+        crate::state::rng_state(&self.0)
+    }
+
+    /// Creates a new [`RngComponent`] instance from a raw internal state returned by
+    /// [`RngComponent::state`], continuing the exact sequence of the saved RNG.
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    #[must_use]
+    pub fn from_state(state: u64) -> Self {
+        Self::from_rng(crate::state::from_state(state))
+    }
+
+    /// Creates an exact copy of the [`RngComponent`], with identical internal state,
+    /// so both will produce the same sequence of values. Unlike [`Clone::clone`], this
+    /// does not advance the state of the original.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    /// let mut copy = rng.clone_exact();
+    ///
+    /// assert_eq!(rng.u64(..), copy.u64(..));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn clone_exact(&self) -> Self {
+        Self(self.0.clone(), self.1)
+    }
+}
+
+impl Clone for RngComponent {
+    /// Creates a new [`RngComponent`] seeded from a single draw of this one, so the two
+    /// diverge deterministically. Use [`RngComponent::clone_exact`] for an identical copy.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::from(&self.0)
+    }
+}
+
+impl PartialEq for RngComponent {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for RngComponent {}
+
+#[cfg(feature = "serialize")]
+crate::state::impl_newtype_serde!(RngComponent, "RngComponent");
+
+#[cfg(feature = "serialize")]
+impl core::hash::Hash for RngComponent {
+    /// Hashes the internal state of the [`RngComponent`], consistent with its [`PartialEq`]
+    /// implementation.
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        crate::state::rng_state(&self.0).hash(state);
     }
 }
 
@@ -81,20 +449,13 @@ impl DelegatedRng for RngComponent {
     type Source = Rng;
 
     #[inline]
-    #[must_use]
     fn get_mut(&mut self) -> &mut Self::Source {
         &mut self.0
     }
 
     #[inline]
-    fn weighted_sample_mut<'a, T, F>(
-        &'a mut self,
-        list: &'a mut [T],
-        weight_sampler: F,
-    ) -> Option<&'a mut T>
-    where
-        F: Fn(&T) -> f64 {
-        self.0.weighted_sample_mut(list, weight_sampler)
+    fn reseed(&mut self, seed: u64) {
+        self.1.reseed(&mut self.0, seed);
     }
 }
 
@@ -110,32 +471,154 @@ impl Default for RngComponent {
 
 impl<T: TurboCore + GenCore> From<&T> for RngComponent {
     #[inline]
-    #[must_use]
     fn from(rng: &T) -> Self {
-        Self(Rng::with_seed(rng.gen_u64()))
+        Self::from_rng(Rng::with_seed(rng.gen_u64()))
     }
 }
 
 impl<T: DelegatedRng> From<&mut T> for RngComponent {
     #[inline]
-    #[must_use]
     fn from(rng: &mut T) -> Self {
-        Self(Rng::with_seed(rng.get_mut().gen_u64()))
+        Self::from_rng(Rng::with_seed(crate::strict::seeding(|| {
+            rng.get_mut().gen_u64()
+        })))
     }
 }
 
 impl<T: DelegatedRng> From<&mut Mut<'_, T>> for RngComponent {
     #[inline]
-    #[must_use]
     fn from(rng: &mut Mut<'_, T>) -> Self {
-        Self(Rng::with_seed(rng.get_mut().gen_u64()))
+        Self::from_rng(Rng::with_seed(crate::strict::seeding(|| {
+            rng.get_mut().gen_u64()
+        })))
     }
 }
 
 impl<T: DelegatedRng + Resource + Send + Sync + 'static> From<&mut ResMut<'_, T>> for RngComponent {
     #[inline]
-    #[must_use]
     fn from(rng: &mut ResMut<'_, T>) -> Self {
-        Self(Rng::with_seed(rng.get_mut().gen_u64()))
+        Self::from_rng(Rng::with_seed(crate::strict::seeding(|| {
+            rng.get_mut().gen_u64()
+        })))
+    }
+}
+
+/// Reflection is implemented by hand rather than with `reflect_value`, as the derive
+/// relies on [`Clone`], which would fork reflected values instead of copying them.
+#[cfg(feature = "bevy_reflect")]
+mod reflect {
+    use super::RngComponent;
+    use bevy::ecs::reflect::ReflectComponent;
+    use bevy::reflect::{
+        std_traits::ReflectDefault, utility::NonGenericTypeInfoCell, FromReflect, FromType,
+        GetTypeRegistration, Reflect, ReflectFromPtr, ReflectMut, ReflectOwned, ReflectRef,
+        TypeInfo, TypeRegistration, Typed, ValueInfo,
+    };
+    use std::any::Any;
+
+    impl GetTypeRegistration for RngComponent {
+        fn get_type_registration() -> TypeRegistration {
+            let mut registration = TypeRegistration::of::<Self>();
+            registration.insert::<ReflectFromPtr>(FromType::<Self>::from_type());
+            registration.insert::<ReflectComponent>(FromType::<Self>::from_type());
+            registration.insert::<ReflectDefault>(FromType::<Self>::from_type());
+            #[cfg(feature = "serialize")]
+            {
+                use bevy::reflect::{ReflectDeserialize, ReflectSerialize};
+
+                registration.insert::<ReflectSerialize>(FromType::<Self>::from_type());
+                registration.insert::<ReflectDeserialize>(FromType::<Self>::from_type());
+            }
+            registration
+        }
+    }
+
+    impl Typed for RngComponent {
+        fn type_info() -> &'static TypeInfo {
+            static CELL: NonGenericTypeInfoCell = NonGenericTypeInfoCell::new();
+            CELL.get_or_set(|| TypeInfo::Value(ValueInfo::new::<Self>()))
+        }
+    }
+
+    impl Reflect for RngComponent {
+        #[inline]
+        fn type_name(&self) -> &str {
+            std::any::type_name::<Self>()
+        }
+
+        #[inline]
+        fn get_type_info(&self) -> &'static TypeInfo {
+            <Self as Typed>::type_info()
+        }
+
+        #[inline]
+        fn into_any(self: Box<Self>) -> Box<dyn Any> {
+            self
+        }
+
+        #[inline]
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        #[inline]
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+
+        #[inline]
+        fn into_reflect(self: Box<Self>) -> Box<dyn Reflect> {
+            self
+        }
+
+        #[inline]
+        fn as_reflect(&self) -> &dyn Reflect {
+            self
+        }
+
+        #[inline]
+        fn as_reflect_mut(&mut self) -> &mut dyn Reflect {
+            self
+        }
+
+        #[inline]
+        fn clone_value(&self) -> Box<dyn Reflect> {
+            Box::new(self.clone_exact())
+        }
+
+        #[inline]
+        fn apply(&mut self, value: &dyn Reflect) {
+            match value.as_any().downcast_ref::<Self>() {
+                Some(value) => *self = value.clone_exact(),
+                None => panic!("Value is not {}.", std::any::type_name::<Self>()),
+            }
+        }
+
+        #[inline]
+        fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), Box<dyn Reflect>> {
+            *self = value.take()?;
+            Ok(())
+        }
+
+        fn reflect_ref(&self) -> ReflectRef<'_> {
+            ReflectRef::Value(self)
+        }
+
+        fn reflect_mut(&mut self) -> ReflectMut<'_> {
+            ReflectMut::Value(self)
+        }
+
+        fn reflect_owned(self: Box<Self>) -> ReflectOwned {
+            ReflectOwned::Value(self)
+        }
+    }
+
+    impl FromReflect for RngComponent {
+        fn from_reflect(reflect: &dyn Reflect) -> Option<Self> {
+            reflect
+                .as_any()
+                .downcast_ref::<Self>()
+                .map(Self::clone_exact)
+        }
     }
 }
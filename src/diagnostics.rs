@@ -0,0 +1,162 @@
+use crate::*;
+use bevy::diagnostic::{Diagnostic, DiagnosticId, Diagnostics};
+use bevy::ecs::schedule::ReportExecutionOrderAmbiguities;
+
+/// A [`Component`] tracking how many times the [`RngComponent`] on the same entity was
+/// drawn from during the last frame. Gets added and updated automatically by the
+/// [`RngDiagnosticsPlugin`].
+///
+/// Draws are counted by how far the internal state advanced since the previous frame, so
+/// every `u64` worth of output counts as a draw, and methods drawing several values count
+/// several times. Reseeding or otherwise replacing the state of an [`RngComponent`] makes the
+/// count for that frame meaningless, with it returning to normal on the following frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "wyrand", feature = "serialize", feature = "std")))
+)]
+pub struct RngDrawCount {
+    last_state: u64,
+    draws: u64,
+}
+
+impl RngDrawCount {
+    /// Returns the number of draws made from the [`RngComponent`] during the last frame.
+    #[inline]
+    #[must_use]
+    pub const fn draws(&self) -> u64 {
+        self.draws
+    }
+}
+
+/// An opt-in debug [`Plugin`] for tracking down ordering problems that break determinism.
+/// Adds an [`RngDrawCount`] to every entity with an [`RngComponent`], updated at the end of
+/// each frame, and reports the total and highest per-entity number of draws as
+/// [`Diagnostics`] under [`RngDiagnosticsPlugin::DRAWS`] and
+/// [`RngDiagnosticsPlugin::MAX_DRAWS`]. A component getting drawn from more or less often
+/// than expected usually means a system is accessing it that shouldn't be.
+///
+/// The [`RngComponent`]s themselves can't tell which systems access them. For that, the plugin
+/// can also enable Bevy's [`ReportExecutionOrderAmbiguities`] with
+/// [`RngDiagnosticsPlugin::with_ambiguity_reports`], which warns about every pair of systems
+/// with conflicting access and no defined order, including ones that conflict over
+/// [`RngComponent`]s. These systems need to be ordered for the app to be deterministic. The
+/// reports cover every system in the app rather than only RNG ones, so they are off by default.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn report_draws(q_draws: Query<(Entity, &RngDrawCount)>) {
+///     for (entity, count) in q_draws.iter() {
+///         println!("{:?} drew {} times last frame", entity, count.draws());
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugin(RngPlugin::new().with_rng_seed(12345))
+///     .add_plugin(RngDiagnosticsPlugin::new().with_ambiguity_reports())
+///     .add_system(report_draws)
+///     .run();
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "wyrand", feature = "serialize", feature = "std")))
+)]
+pub struct RngDiagnosticsPlugin {
+    report_ambiguities: bool,
+}
+
+impl RngDiagnosticsPlugin {
+    /// Create a new [`RngDiagnosticsPlugin`] instance, with Bevy's ambiguity reports left
+    /// as configured by the app.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            report_ambiguities: false,
+        }
+    }
+
+    /// Builder function to also enable Bevy's [`ReportExecutionOrderAmbiguities`], warning
+    /// about every pair of systems in the app with conflicting access and no defined order.
+    /// This includes systems that have nothing to do with RNG, so expect noisy logs in
+    /// larger apps.
+    #[inline]
+    #[must_use]
+    pub const fn with_ambiguity_reports(mut self) -> Self {
+        self.report_ambiguities = true;
+        self
+    }
+
+    /// The total number of draws from all [`RngComponent`]s during the last frame.
+    pub const DRAWS: DiagnosticId =
+        DiagnosticId::from_u128(0x6b1f_36a4_07e2_4c59_9d8e_52b3_c1a0_f473);
+    /// The highest number of draws from a single [`RngComponent`] during the last frame.
+    pub const MAX_DRAWS: DiagnosticId =
+        DiagnosticId::from_u128(0x2a94_e8d1_53c7_4b06_8f1d_7e60_94bc_a215);
+}
+
+impl Plugin for RngDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Diagnostics>()
+            .add_startup_system(setup_diagnostics)
+            .add_system_to_stage(CoreStage::Last, count_draws);
+
+        if self.report_ambiguities {
+            app.init_resource::<ReportExecutionOrderAmbiguities>();
+        }
+    }
+}
+
+fn setup_diagnostics(mut diagnostics: ResMut<'_, Diagnostics>) {
+    diagnostics.add(Diagnostic::new(
+        RngDiagnosticsPlugin::DRAWS,
+        "rng_draws",
+        20,
+    ));
+    diagnostics.add(Diagnostic::new(
+        RngDiagnosticsPlugin::MAX_DRAWS,
+        "rng_max_draws",
+        20,
+    ));
+}
+
+fn count_draws(
+    mut commands: Commands<'_, '_>,
+    mut diagnostics: ResMut<'_, Diagnostics>,
+    mut q_rng: Query<'_, '_, (Entity, &RngComponent, Option<&mut RngDrawCount>)>,
+) {
+    let mut total = 0_u64;
+    let mut max = 0_u64;
+
+    for (entity, rng, count) in q_rng.iter_mut() {
+        let state = crate::state::rng_state(&rng.0);
+
+        match count {
+            Some(mut count) => {
+                let draws = crate::state::draws_between(count.last_state, state);
+
+                *count = RngDrawCount {
+                    last_state: state,
+                    draws,
+                };
+
+                total = total.saturating_add(draws);
+                max = max.max(draws);
+            }
+            // Counting starts from the first frame the component is seen
+            None => {
+                commands.entity(entity).insert(RngDrawCount {
+                    last_state: state,
+                    draws: 0,
+                });
+            }
+        }
+    }
+
+    diagnostics.add_measurement(RngDiagnosticsPlugin::DRAWS, || total as f64);
+    diagnostics.add_measurement(RngDiagnosticsPlugin::MAX_DRAWS, || max as f64);
+}
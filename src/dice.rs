@@ -0,0 +1,175 @@
+use crate::*;
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+/// Errors that can occur when parsing dice notation with [`DelegatedRng::roll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiceParseError {
+    /// The notation was empty, or contained an empty term (such as `"1d6+"`).
+    Empty,
+    /// The number of dice to roll in a term was zero, more than 1000 or not a valid integer.
+    InvalidCount,
+    /// The number of sides of the dice in a term was missing, zero or not a valid integer.
+    InvalidSides,
+    /// A flat modifier term was not a valid integer.
+    InvalidModifier,
+    /// The notation could produce a total that does not fit within an `i64`.
+    Overflow,
+}
+
+impl Display for DiceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "dice notation contains an empty term",
+            Self::InvalidCount => "invalid number of dice in dice notation",
+            Self::InvalidSides => "invalid number of sides in dice notation",
+            Self::InvalidModifier => "invalid modifier in dice notation",
+            Self::Overflow => "dice notation total could overflow an i64",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DiceParseError {}
+
+/// The most dice a single term can roll, so that user supplied notation such as
+/// `"4000000000d6"` can't stall the frame by looping over billions of dice.
+const MAX_DICE: u32 = 1_000;
+
+/// A single signed term of a dice expression, either rolling a number of dice or
+/// adding a flat modifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DiceTerm {
+    Dice {
+        count: u32,
+        sides: u32,
+        negative: bool,
+    },
+    Modifier(i64),
+}
+
+impl DiceTerm {
+    /// Rolls the term with the given RNG source.
+    pub(crate) fn roll<R: TurboRand>(&self, rng: &R) -> i64 {
+        match *self {
+            Self::Dice {
+                count,
+                sides,
+                negative,
+            } => {
+                let total: i64 = (0..count).map(|_| i64::from(rng.u32(1..=sides))).sum();
+
+                if negative {
+                    -total
+                } else {
+                    total
+                }
+            }
+            Self::Modifier(modifier) => modifier,
+        }
+    }
+}
+
+/// Parses dice notation such as `"4d8-1+1d4"` into its terms, validating that rolling
+/// them can never overflow an `i64`. Whitespace is ignored, and the count of a dice term
+/// defaults to one when left out (`"d20"`).
+pub(crate) fn parse(notation: &str) -> Result<Vec<DiceTerm>, DiceParseError> {
+    let notation: String = notation.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut terms = Vec::new();
+    let mut rest = notation.as_str();
+    let mut negative = false;
+    let (mut min, mut max) = (0_i64, 0_i64);
+
+    if let Some(stripped) = rest.strip_prefix('-') {
+        rest = stripped;
+        negative = true;
+    }
+
+    loop {
+        let end = rest.find(['+', '-']).unwrap_or(rest.len());
+        let term = parse_term(&rest[..end], negative)?;
+
+        let (low, high) = match term {
+            DiceTerm::Dice {
+                count,
+                sides,
+                negative,
+            } => {
+                let low = i64::from(count);
+                let high = low
+                    .checked_mul(i64::from(sides))
+                    .ok_or(DiceParseError::Overflow)?;
+
+                if negative {
+                    (-high, -low)
+                } else {
+                    (low, high)
+                }
+            }
+            DiceTerm::Modifier(modifier) => (modifier, modifier),
+        };
+
+        min = min.checked_add(low).ok_or(DiceParseError::Overflow)?;
+        max = max.checked_add(high).ok_or(DiceParseError::Overflow)?;
+        terms.push(term);
+
+        match rest[end..].chars().next() {
+            Some(sign) => {
+                negative = sign == '-';
+                rest = &rest[end + 1..];
+            }
+            None => break,
+        }
+    }
+
+    Ok(terms)
+}
+
+fn parse_term(term: &str, negative: bool) -> Result<DiceTerm, DiceParseError> {
+    if term.is_empty() {
+        return Err(DiceParseError::Empty);
+    }
+
+    match term.find(['d', 'D']) {
+        Some(index) => {
+            let (count, sides) = (&term[..index], &term[index + 1..]);
+
+            let count = if count.is_empty() {
+                1
+            } else {
+                parse_digits::<u32>(count)
+                    .filter(|count| (1..=MAX_DICE).contains(count))
+                    .ok_or(DiceParseError::InvalidCount)?
+            };
+
+            let sides = parse_digits::<u32>(sides)
+                .filter(|&sides| sides > 0)
+                .ok_or(DiceParseError::InvalidSides)?;
+
+            Ok(DiceTerm::Dice {
+                count,
+                sides,
+                negative,
+            })
+        }
+        None => {
+            let modifier = parse_digits::<i64>(term).ok_or(DiceParseError::InvalidModifier)?;
+
+            Ok(DiceTerm::Modifier(if negative {
+                -modifier
+            } else {
+                modifier
+            }))
+        }
+    }
+}
+
+/// Parses a string made up solely of ASCII digits, rejecting any signs that
+/// [`str::parse`] would otherwise accept.
+fn parse_digits<T: core::str::FromStr>(digits: &str) -> Option<T> {
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        digits.parse().ok()
+    } else {
+        None
+    }
+}
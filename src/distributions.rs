@@ -0,0 +1,194 @@
+use crate::*;
+
+/// Lambda at and above which Poisson sampling switches from Knuth's multiplication
+/// method, which is linear in lambda, to transformed rejection.
+pub(crate) const POISSON_PTRS_THRESHOLD: f64 = 10.0;
+
+/// Samples a Poisson distribution with Knuth's multiplication method, by counting how
+/// many uniform draws can be multiplied together before dropping below `e^-lambda`.
+#[inline]
+pub(crate) fn poisson_knuth<R: TurboRand>(rng: &R, lambda: f64) -> u64 {
+    let limit = (-lambda).exp();
+    let mut product = rng.f64();
+    let mut count = 0;
+
+    while product > limit {
+        product *= rng.f64();
+        count += 1;
+    }
+
+    count
+}
+
+/// Samples a Poisson distribution with Hörmann's transformed rejection with squeeze
+/// (PTRS), which takes a constant expected number of draws regardless of lambda.
+pub(crate) fn poisson_ptrs<R: TurboRand>(rng: &R, lambda: f64) -> u64 {
+    let sqrt_lambda = lambda.sqrt();
+    let ln_lambda = lambda.ln();
+    let b = 0.931 + 2.53 * sqrt_lambda;
+    let a = -0.059 + 0.02483 * b;
+    let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+
+    loop {
+        let u = rng.f64() - 0.5;
+        let v = rng.f64();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+
+        if us >= 0.07 && v <= v_r {
+            return k as u64;
+        }
+
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+
+        if (v * inv_alpha / (a / (us * us) + b)).ln() <= -lambda + k * ln_lambda - ln_gamma(k + 1.0)
+        {
+            return k as u64;
+        }
+    }
+}
+
+/// Samples a gamma distribution with the given `shape` and a scale of 1, using
+/// Marsaglia & Tsang's squeeze and rejection method. Shapes below 1 are sampled from
+/// `shape + 1` and then scaled down by `u^(1 / shape)`, which keeps the method exact.
+pub(crate) fn gamma_marsaglia_tsang<R: TurboRand>(rng: &R, shape: f64) -> f64 {
+    if shape < 1.0 {
+        // Shift the draw into (0, 1] so that the result is never scaled down to zero early
+        let boost = (1.0 - rng.f64()).powf(1.0 / shape);
+
+        return gamma_marsaglia_tsang(rng, shape + 1.0) * boost;
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+
+    loop {
+        let x = standard_normal(rng);
+        let v = 1.0 + c * x;
+
+        if v <= 0.0 {
+            continue;
+        }
+
+        let v = v * v * v;
+        let u = rng.f64();
+        let x_squared = x * x;
+
+        if u < 1.0 - 0.0331 * x_squared * x_squared
+            || u.ln() < 0.5 * x_squared + d * (1.0 - v + v.ln())
+        {
+            return d * v;
+        }
+    }
+}
+
+/// Samples a standard normal distribution with the Box-Muller transform.
+#[inline]
+fn standard_normal<R: TurboRand>(rng: &R) -> f64 {
+    let radius = (-2.0 * (1.0 - rng.f64()).ln()).sqrt();
+    let angle = core::f64::consts::TAU * rng.f64();
+
+    radius * angle.cos()
+}
+
+/// Natural log of the gamma function for positive `x`, using a Stirling series, with
+/// small values first shifted up via the recurrence `Γ(x + 1) = xΓ(x)`.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 10] = [
+        8.333_333_333_333_333e-2,
+        -2.777_777_777_777_778e-3,
+        7.936_507_936_507_937e-4,
+        -5.952_380_952_380_952e-4,
+        8.417_508_417_508_418e-4,
+        -1.917_526_917_526_918e-3,
+        6.410_256_410_256_41e-3,
+        -2.955_065_359_477_124e-2,
+        1.796_443_723_688_307e-1,
+        -1.392_432_216_905_9,
+    ];
+    const LN_2PI: f64 = 1.837_877_066_409_345_3;
+
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+
+    let shift = if x < 7.0 { (7.0 - x).floor() } else { 0.0 };
+    let x0 = x + shift;
+    let x2 = (1.0 / x0) * (1.0 / x0);
+
+    let series = COEFFICIENTS
+        .iter()
+        .rev()
+        .fold(0.0, |acc, coefficient| acc * x2 + coefficient);
+
+    let mut result = series / x0 + 0.5 * LN_2PI + (x0 - 0.5) * x0.ln() - x0;
+    let mut shifted = x0;
+
+    for _ in 0..shift as u32 {
+        shifted -= 1.0;
+        result -= shifted.ln();
+    }
+
+    result
+}
+
+/// Selects up to `amount` distinct indices from `weights` with the A-ExpJ weighted reservoir
+/// sampling algorithm from [Efraimidis & Spirakis](https://doi.org/10.1016/j.ipl.2005.11.003),
+/// which only draws new keys for the items that enter the reservoir. Keys are kept in log
+/// space, like [`DelegatedRng::sample_weighted_iter`]. Indices with a weight that is zero,
+/// negative or not finite are skipped, so fewer than `amount` may be returned.
+pub(crate) fn weighted_reservoir<R: TurboRand>(
+    rng: &R,
+    weights: &[f64],
+    amount: usize,
+) -> Vec<usize> {
+    let mut candidates = weights
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|&(_, weight)| weight > 0.0 && weight.is_finite());
+
+    let mut reservoir: Vec<(f64, usize)> = candidates
+        .by_ref()
+        .take(amount)
+        .map(|(index, weight)| ((1.0 - rng.f64()).ln() / weight, index))
+        .collect();
+
+    if amount > 0 && reservoir.len() == amount {
+        let lowest = |reservoir: &[(f64, usize)]| {
+            (1..reservoir.len()).fold(0, |lowest, position| {
+                if reservoir[position].0 < reservoir[lowest].0 {
+                    position
+                } else {
+                    lowest
+                }
+            })
+        };
+
+        let mut position = lowest(&reservoir);
+        let mut threshold = reservoir[position].0;
+        let mut skip = (1.0 - rng.f64()).ln() / threshold;
+
+        for (index, weight) in candidates {
+            skip -= weight;
+
+            if skip <= 0.0 {
+                // The new key is drawn uniformly between the threshold and 1, so the
+                // item is guaranteed to displace the lowest key in the reservoir.
+                let low = (threshold * weight).exp();
+                let key = (low + (1.0 - low) * (1.0 - rng.f64())).ln() / weight;
+
+                reservoir[position] = (key, index);
+
+                position = lowest(&reservoir);
+                threshold = reservoir[position].0;
+                skip = (1.0 - rng.f64()).ln() / threshold;
+            }
+        }
+    }
+
+    reservoir.into_iter().map(|(_, index)| index).collect()
+}
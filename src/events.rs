@@ -0,0 +1,79 @@
+use crate::*;
+
+/// An event for reseeding the [`GlobalRng`] at runtime, such as when starting a new
+/// daily challenge with a date derived seed. Events sent are handled by [`RngPlugin`]
+/// during [`CoreStage::First`], so any systems running afterwards in the same frame
+/// will observe the reseeded [`GlobalRng`]. If more than one event is sent in a frame,
+/// the last one sent wins. The reseeded [`GlobalRng`] produces the same sequence as
+/// `GlobalRng::with_seed(seed)`.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn start_daily_challenge(mut reseed: EventWriter<ReseedGlobalRng>) {
+///     reseed.send(ReseedGlobalRng { seed: 20221201 });
+/// }
+/// # bevy::ecs::system::assert_is_system(start_daily_challenge);
+/// ```
+#[cfg(feature = "wyrand")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct ReseedGlobalRng {
+    /// The seed to reseed the [`GlobalRng`] with.
+    pub seed: u64,
+}
+
+/// An event for reseeding the [`GlobalChaChaRng`] at runtime. Events sent are handled
+/// by [`RngPlugin`] during [`CoreStage::First`], so any systems running afterwards in
+/// the same frame will observe the reseeded [`GlobalChaChaRng`]. If more than one event
+/// is sent in a frame, the last one sent wins.
+#[cfg(feature = "chacha")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+pub struct ReseedGlobalChaChaRng {
+    /// The seed to reseed the [`GlobalChaChaRng`] with.
+    pub seed: [u8; 40],
+}
+
+/// Reseeds the [`GlobalRng`] with the last [`ReseedGlobalRng`] event sent.
+#[cfg(feature = "wyrand")]
+pub(crate) fn reseed_global_rng(
+    mut events: EventReader<'_, '_, ReseedGlobalRng>,
+    mut global: ResMut<'_, GlobalRng>,
+) {
+    if let Some(event) = events.iter().last() {
+        global.reseed(event.seed);
+    }
+}
+
+/// Reseeds the [`GlobalChaChaRng`] with the last [`ReseedGlobalChaChaRng`] event sent.
+#[cfg(feature = "chacha")]
+pub(crate) fn reseed_global_chacha_rng(
+    mut events: EventReader<'_, '_, ReseedGlobalChaChaRng>,
+    mut global: ResMut<'_, GlobalChaChaRng>,
+) {
+    if let Some(event) = events.iter().last() {
+        global.reseed(event.seed);
+    }
+}
+
+/// The [`Timer`] for reseeding the [`GlobalChaChaRng`], as set up with
+/// [`RngPlugin::with_secure_reseed_interval`].
+#[cfg(feature = "chacha")]
+#[derive(Resource)]
+pub(crate) struct SecureReseedTimer(pub(crate) Timer);
+
+/// Reseeds the [`GlobalChaChaRng`] with fresh entropy whenever the [`SecureReseedTimer`]
+/// finishes. Reseeds only once even if several intervals elapsed within the same frame.
+#[cfg(feature = "chacha")]
+pub(crate) fn reseed_global_chacha_rng_on_interval(
+    time: Res<'_, Time>,
+    mut timer: ResMut<'_, SecureReseedTimer>,
+    mut global: ResMut<'_, GlobalChaChaRng>,
+) {
+    if timer.0.tick(time.delta()).just_finished() {
+        *global = GlobalChaChaRng::new();
+    }
+}
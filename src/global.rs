@@ -3,3 +3,6 @@ pub mod rng;
 
 #[cfg(feature = "chacha")]
 pub mod chacha;
+
+#[cfg(feature = "atomic")]
+pub mod atomic;
@@ -0,0 +1,88 @@
+use crate::*;
+
+/// A Global [`AtomicRng`] instance, meant for use as a Resource that can be drawn from
+/// with a shared `Res<AtomicGlobalRng>` reference. Gets created automatically with
+/// [`RngPlugin`], or can be created and added manually.
+///
+/// Unlike [`GlobalRng`], which requires `ResMut` access and so forces systems using it to
+/// run one at a time, [`AtomicGlobalRng`] keeps its state in an atomic, allowing any
+/// number of systems to draw from it in parallel. This comes at the cost of atomic
+/// operations for every draw, and more importantly, of determinism: the order in which
+/// parallel systems draw values is up to the scheduler, so the values each system receives
+/// will vary from run to run even with a fixed seed. It is only suitable for cases where
+/// determinism doesn't matter, such as cosmetic jitter.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Component)]
+/// struct Particle;
+///
+/// fn jitter_particles(
+///     rng: Res<AtomicGlobalRng>,
+///     mut q_particles: Query<&mut Transform, With<Particle>>,
+/// ) {
+///     for mut transform in q_particles.iter_mut() {
+///         transform.translation.x += rng.get().f32_normalized() * 0.1;
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(jitter_particles);
+/// ```
+#[derive(Debug, Resource)]
+#[cfg_attr(docsrs, doc(cfg(feature = "atomic")))]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct AtomicGlobalRng(pub(crate) AtomicRng);
+
+impl AtomicGlobalRng {
+    /// Create a new [`AtomicGlobalRng`] instance with a randomised seed.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self(AtomicRng::new())
+    }
+
+    /// Create a new [`AtomicGlobalRng`] instance with a given seed. Draws are only
+    /// reproducible if they are made from a single thread in a fixed order.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self(AtomicRng::with_seed(seed))
+    }
+
+    /// Returns a shared reference to the internal [`AtomicRng`], which can be drawn from
+    /// directly as it only needs `&self` to generate values.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self) -> &AtomicRng {
+        &self.0
+    }
+}
+
+impl DelegatedRng for AtomicGlobalRng {
+    type Source = AtomicRng;
+
+    /// Returns the internal [`TurboRand`] reference. As [`AtomicRng`] is thread-safe,
+    /// prefer [`AtomicGlobalRng::get`] for drawing from a shared reference.
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        &mut self.0
+    }
+}
+
+impl Default for AtomicGlobalRng {
+    /// Creates a default [`AtomicGlobalRng`] instance. The instance will
+    /// be initialised with a randomised seed, so this is **not**
+    /// deterministic.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsRef<AtomicRng> for AtomicGlobalRng {
+    fn as_ref(&self) -> &AtomicRng {
+        self.get()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use core::fmt::{self, Display};
 
 /// A Global [`ChaChaRng`] instance, meant for use as a Resource. Gets
 /// created automatically with [`RngPlugin`], or can be created
@@ -6,7 +7,7 @@ use crate::*;
 #[derive(Debug, Resource)]
 #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-pub struct GlobalChaChaRng(ChaChaRng);
+pub struct GlobalChaChaRng(pub(crate) ChaChaRng);
 
 unsafe impl Sync for GlobalChaChaRng {}
 
@@ -24,6 +25,135 @@ impl GlobalChaChaRng {
     pub fn with_seed(seed: [u8; 40]) -> Self {
         Self(ChaChaRng::with_seed(seed))
     }
+
+    /// Create a new [`GlobalChaChaRng`] instance with a given seed, rejecting seeds that are
+    /// obviously weak, such as an array left zeroed by mistake. [`GlobalChaChaRng::with_seed`]
+    /// accepts any seed, for callers that know what they are doing.
+    ///
+    /// Only catches seeds made up of a single repeated byte, so passing this check does not
+    /// mean a seed has enough entropy. Seeds should come from a secure source, such as
+    /// [`GlobalChaChaRng::random_bytes`] of another secure RNG.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WeakSeedError::AllZero`] if every byte of the seed is zero, or
+    /// [`WeakSeedError::RepeatedByte`] if every byte of the seed is the same non-zero value.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// assert_eq!(
+    ///     GlobalChaChaRng::with_seed_checked([0; 40]).unwrap_err(),
+    ///     WeakSeedError::AllZero
+    /// );
+    ///
+    /// let seed: [u8; 40] = GlobalChaChaRng::new().random_bytes();
+    ///
+    /// assert!(GlobalChaChaRng::with_seed_checked(seed).is_ok());
+    /// ```
+    #[inline]
+    pub fn with_seed_checked(seed: [u8; 40]) -> Result<Self, WeakSeedError> {
+        let first = seed[0];
+
+        if seed.iter().all(|&byte| byte == first) {
+            return Err(if first == 0 {
+                WeakSeedError::AllZero
+            } else {
+                WeakSeedError::RepeatedByte(first)
+            });
+        }
+
+        Ok(Self::with_seed(seed))
+    }
+
+    /// Create a new [`GlobalChaChaRng`] instance from an existing [`ChaChaRng`], taking ownership of it
+    /// and preserving its exact internal state, so the [`GlobalChaChaRng`] continues the same sequence.
+    #[inline]
+    #[must_use]
+    pub fn from_rng(rng: ChaChaRng) -> Self {
+        Self(rng)
+    }
+
+    /// Generates `N` cryptographically secure random bytes from the ChaCha8 core in one
+    /// call, such as for session tokens or nonces.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut global = GlobalChaChaRng::new();
+    ///
+    /// let token: [u8; 32] = global.random_bytes();
+    ///
+    /// assert_eq!(token.len(), 32);
+    /// ```
+    #[inline]
+    pub fn random_bytes<const N: usize>(&mut self) -> [u8; N] {
+        self.0.gen()
+    }
+
+    /// Return a compatibility shim for working with crates from the `rand`
+    /// ecosystem that require a [`CryptoRng`](rand_core::CryptoRng) source.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    #[inline]
+    pub fn as_secure_rand(&mut self) -> SecureRandBorrowed<'_> {
+        SecureRandBorrowed::from(&mut self.0)
+    }
+
+    /// Forks a new, standalone [`ChaChaRng`] from the [`GlobalChaChaRng`], advancing the
+    /// global state once in order to seed it. The forked [`ChaChaRng`] is independent
+    /// from the [`GlobalChaChaRng`], but deterministic if the [`GlobalChaChaRng`] is seeded, making
+    /// it suitable for use in non-ECS contexts such as background asset generation.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn generate_in_background(mut global: ResMut<GlobalChaChaRng>) {
+    ///     let rng = global.fork();
+    ///
+    ///     std::thread::spawn(move || {
+    ///         let noise: Vec<f32> = (0..16).map(|_| rng.f32()).collect();
+    ///
+    ///         println!("Generated noise: {:?}", noise);
+    ///     });
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fork(&mut self) -> ChaChaRng {
+        self.0.fork()
+    }
+
+    /// Creates a new [`RngComponent`] seeded with secure entropy from the
+    /// [`GlobalChaChaRng`], for performance critical work that doesn't need to be
+    /// cryptographically secure. Seeding flows from the higher quality source to the lower
+    /// quality one, never the other way around, and is deterministic if the
+    /// [`GlobalChaChaRng`] is seeded. Advances the global state once.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// #[derive(Debug, Component)]
+    /// struct Particle;
+    ///
+    /// fn spawn_particles(mut commands: Commands, mut secure: ResMut<GlobalChaChaRng>) {
+    ///     commands.spawn((Particle, secure.fork_insecure()));
+    /// }
+    /// # bevy::ecs::system::assert_is_system(spawn_particles);
+    /// ```
+    #[cfg(feature = "wyrand")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "chacha", feature = "wyrand"))))]
+    #[inline]
+    #[must_use]
+    pub fn fork_insecure(&mut self) -> RngComponent {
+        RngComponent::from(self)
+    }
 }
 
 impl DelegatedRng for GlobalChaChaRng {
@@ -52,17 +182,6 @@ impl DelegatedRng for GlobalChaChaRng {
     fn get_mut(&mut self) -> &mut Self::Source {
         &mut self.0
     }
-
-    #[inline]
-    fn weighted_sample_mut<'a, T, F>(
-        &'a mut self,
-        list: &'a mut [T],
-        weight_sampler: F,
-    ) -> Option<&'a mut T>
-    where
-        F: Fn(&T) -> f64 {
-        self.0.weighted_sample_mut(list, weight_sampler)
-    }
 }
 
 impl Default for GlobalChaChaRng {
@@ -80,3 +199,30 @@ impl AsMut<ChaChaRng> for GlobalChaChaRng {
         self.get_mut()
     }
 }
+
+/// Errors that can occur when creating a [`GlobalChaChaRng`] with
+/// [`GlobalChaChaRng::with_seed_checked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+pub enum WeakSeedError {
+    /// Every byte of the seed was zero.
+    AllZero,
+    /// Every byte of the seed was the same value. Contains the repeated byte.
+    RepeatedByte(u8),
+}
+
+impl Display for WeakSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AllZero => f.write_str("secure seed is all zeroes"),
+            Self::RepeatedByte(byte) => write!(
+                f,
+                "secure seed is the byte {:#04x} repeated, which has no entropy",
+                byte
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeakSeedError {}
@@ -1,28 +1,407 @@
 use crate::*;
+use core::{
+    fmt,
+    ops::Deref,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 /// A Global [`Rng`] instance, meant for use as a Resource. Gets
 /// created automatically with [`RngPlugin`], or can be created
 /// and added manually.
-#[derive(Debug, Resource)]
+///
+/// Like [`RngComponent`], [`GlobalRng`]s compare equal when their internal states are
+/// identical, and implement [`Hash`](core::hash::Hash) with the `serialize` feature enabled.
+#[derive(Resource)]
 #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
-#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
-pub struct GlobalRng(Rng);
+pub struct GlobalRng(
+    pub(crate) Rng,
+    crate::state::DrawStats,
+    /// Guards the internal [`Rng`] while it is being accessed from a shared reference,
+    /// holding the token of the thread accessing it, or zero when it is free.
+    AtomicUsize,
+    /// Whether draws are only allowed within seeding contexts.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    bool,
+);
 
+// Shared access to the internal `Rng` only happens through `GlobalRng::get_cell`, which
+// guards it so that only a single thread can access it at a time.
 unsafe impl Sync for GlobalRng {}
 
 impl GlobalRng {
+    /// The number of bytes a [`GlobalRng`] takes up when serialized with a compact binary
+    /// format using fixed-width integers, such as `bincode`'s default configuration. This is
+    /// the 8 bytes of the internal WyRand state, and is stable for a given `turborand` version.
+    /// Self-describing formats such as JSON take up more space, and varint encodings may
+    /// take up less or more depending on the state.
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    pub const SERIALIZED_SIZE: usize = core::mem::size_of::<u64>();
+
     /// Create a new [`GlobalRng`] instance with a randomised seed.
     #[inline]
     #[must_use]
     pub fn new() -> Self {
-        Self(Rng::new())
+        Self::from_rng(Rng::new())
     }
 
     /// Create a new [`GlobalRng`] instance with a given seed.
     #[inline]
     #[must_use]
     pub fn with_seed(seed: u64) -> Self {
-        Self(Rng::with_seed(seed))
+        Self::from_rng(Rng::with_seed(seed))
+    }
+
+    /// Create a new [`GlobalRng`] instance seeded from a string, such as a world name shared
+    /// between players. The seed is the 64-bit FNV-1a hash of the string's UTF-8 bytes, which
+    /// is fully specified and implemented by this crate, so the same string always maps to
+    /// the same seed on every platform and in every version of this crate.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut world_1 = GlobalRng::with_string_seed("Glacier Peaks");
+    /// let mut world_2 = GlobalRng::with_string_seed("Glacier Peaks");
+    ///
+    /// assert_eq!(world_1.u64(..), world_2.u64(..));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_string_seed(name: &str) -> Self {
+        Self::with_seed(crate::hash::fnv1a(name.as_bytes()))
+    }
+
+    /// Create a new [`GlobalRng`] instance from an existing [`Rng`], taking ownership of it
+    /// and preserving its exact internal state, so the [`GlobalRng`] continues the same sequence.
+    #[inline]
+    #[must_use]
+    pub fn from_rng(rng: Rng) -> Self {
+        let stats = crate::state::DrawStats::new(&rng);
+
+        Self(rng, stats, AtomicUsize::new(0), false)
+    }
+
+    /// Create a new [`GlobalRng`] instance seeded from any other [`TurboRand`] source, drawing
+    /// a single `u64` from it as the seed. The [`GlobalRng`] is deterministic as long as the
+    /// source's state is, mirroring how an [`RngComponent`] gets seeded from a [`GlobalRng`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let source = Rng::with_seed(12345);
+    ///
+    /// let mut global = GlobalRng::from_source(&source);
+    ///
+    /// println!("Rolled a {}", global.u32(1..=6));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_source<S: TurboRand>(source: &S) -> Self {
+        Self::with_seed(source.u64(..))
+    }
+
+    /// Create a new [`GlobalRng`] instance with a seed given as bytes, interpreted in
+    /// little-endian order. Equivalent to calling [`GlobalRng::with_seed`] with
+    /// [`u64::from_le_bytes`], which is useful for seeds derived from hashes or network data.
+    #[inline]
+    #[must_use]
+    pub fn with_seed_bytes(bytes: [u8; 8]) -> Self {
+        Self::with_seed(u64::from_le_bytes(bytes))
+    }
+
+    /// Forks a new, standalone [`Rng`] from the [`GlobalRng`], advancing the
+    /// global state once in order to seed it. The forked [`Rng`] is independent
+    /// from the [`GlobalRng`], but deterministic if the [`GlobalRng`] is seeded, making
+    /// it suitable for use in non-ECS contexts such as background asset generation.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn generate_in_background(mut global: ResMut<GlobalRng>) {
+    ///     let rng = global.fork();
+    ///
+    ///     std::thread::spawn(move || {
+    ///         let noise: Vec<f32> = (0..16).map(|_| rng.f32()).collect();
+    ///
+    ///         println!("Generated noise: {:?}", noise);
+    ///     });
+    /// }
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn fork(&mut self) -> Rng {
+        self.0.fork()
+    }
+
+    /// Reseeds the [`GlobalRng`] in place with a new seed, resetting its
+    /// internal state. Reseeding with the same seed will always yield the
+    /// same sequence of values afterwards, making it possible to re-establish
+    /// determinism mid-run without replacing the whole [`GlobalRng`]. The sequence
+    /// is the same as that of a new [`GlobalRng::with_seed`] with the same seed, so
+    /// reseeding restarts a level exactly as it played the first time.
+    #[inline]
+    pub fn reseed(&mut self, seed: u64) {
+        self.1.reseed(&mut self.0, seed);
+    }
+
+    /// Advances the internal state of the [`GlobalRng`] by `n` draws, as if `n`
+    /// `u64` values had been generated and discarded. Useful for fast-forwarding
+    /// an RNG in order to resync with a known state, such as from a server.
+    ///
+    /// A draw here is a single step of the underlying WyRand generator. Most
+    /// delegated methods consume one step, though 128-bit values consume two and
+    /// range based methods may consume more in order to avoid bias.
+    ///
+    /// With the `serialize` feature enabled, this is a constant time jump, as
+    /// WyRand's state advances by a fixed increment for every draw. Otherwise,
+    /// the draws are generated and discarded, so the cost is linear in `n`.
+    #[inline]
+    pub fn skip(&mut self, n: u64) {
+        crate::state::skip(&self.0, n);
+    }
+
+    /// Sets whether the [`GlobalRng`] is in strict mode, which enforces only using it for
+    /// seeding other RNG sources rather than as a direct source of entropy. In strict mode,
+    /// any draw through [`DelegatedRng`] methods panics, unless it is made while creating
+    /// an [`RngComponent`] from the [`GlobalRng`], such as with [`RngComponent::from`],
+    /// [`RngCommandsExt::attach_rng`] or [`LocalRng`]. Forking with [`GlobalRng::fork`]
+    /// and reseeding remain allowed as well.
+    ///
+    /// Draws through [`GlobalRng::get_cell`] are not checked. Strict mode is usually enabled
+    /// through [`RngPlugin::strict_global`], and is not kept when replacing the [`GlobalRng`]
+    /// resource, such as when restoring an [`RngSnapshot`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut global = GlobalRng::with_seed(12345);
+    ///
+    /// global.set_strict(true);
+    ///
+    /// let mut rng = RngComponent::from(&mut global);
+    ///
+    /// let damage = rng.u32(1..=6);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+    #[inline]
+    pub fn set_strict(&mut self, strict: bool) {
+        self.3 = strict;
+    }
+
+    /// Returns whether the [`GlobalRng`] is in strict mode. See [`GlobalRng::set_strict`] for
+    /// more details.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+    #[inline]
+    #[must_use]
+    pub const fn is_strict(&self) -> bool {
+        self.3
+    }
+
+    /// Returns a handle to the internal [`Rng`] from a shared reference, for drawing values
+    /// in systems that only have `Res<GlobalRng>` access, such as when a `ResMut<GlobalRng>`
+    /// would conflict with other borrows. The handle dereferences to the [`Rng`], so any
+    /// [`TurboRand`] method can be used with it.
+    ///
+    /// **This breaks the determinism guarantees of [`GlobalRng`].** Systems with shared access
+    /// to the same resource are free to run in parallel, so the order in which they draw
+    /// values from the handle is up to the scheduler and will vary from run to run, even with
+    /// a fixed seed. Draws made through the handle are only reproducible when no other system
+    /// borrowing the [`GlobalRng`] can run at the same time. Prefer `ResMut<GlobalRng>`, or
+    /// [`AtomicGlobalRng`] with the `atomic` feature for draws that are meant to be parallel.
+    ///
+    /// Access through the handle is exclusive, so other threads taking a handle at the same
+    /// time wait until it is dropped. Handles are best used as temporaries for single draws.
+    ///
+    /// # Panics
+    ///
+    /// Like [`RefCell::borrow_mut`](core::cell::RefCell::borrow_mut), panics if the same
+    /// thread already holds a handle to this [`GlobalRng`]. This includes formatting,
+    /// comparing, hashing, serializing or snapshotting the [`GlobalRng`] while holding a
+    /// handle, as those access the internal [`Rng`] through a handle as well.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// #[derive(Debug, Component)]
+    /// struct Torch {
+    ///     lit: bool,
+    /// }
+    ///
+    /// fn flicker_torches(global: Res<GlobalRng>, mut q_torches: Query<&mut Torch>) {
+    ///     for mut torch in q_torches.iter_mut() {
+    ///         torch.lit = global.get_cell().bool();
+    ///     }
+    /// }
+    /// # bevy::ecs::system::assert_is_system(flicker_torches);
+    ///
+    /// let global = GlobalRng::with_seed(12345);
+    /// let mut clone = GlobalRng::with_seed(12345);
+    ///
+    /// assert_eq!(global.get_cell().u64(..), clone.u64(..));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_cell(&self) -> GlobalRngCell<'_> {
+        let token = thread_token();
+
+        while let Err(holder) =
+            self.2
+                .compare_exchange_weak(0, token, Ordering::Acquire, Ordering::Relaxed)
+        {
+            // Waiting on a handle held by this very thread would never return
+            assert!(holder != token, "GlobalRng already borrowed via get_cell");
+
+            core::hint::spin_loop();
+        }
+
+        GlobalRngCell { global: self }
+    }
+
+    /// Returns the number of draws made from the [`GlobalRng`] since it was created, for
+    /// profiling which systems are RNG heavy. A draw is a single `u64` sized step of the
+    /// underlying WyRand generator, as with [`GlobalRng::skip`], so skipped draws are counted too.
+    /// This is not a count of method calls: most delegated methods take one step, but 128-bit
+    /// values take two and range based methods may take more in order to avoid bias.
+    ///
+    /// The count is derived from how far the internal state has advanced, so every draw is
+    /// counted, including ones made through [`DelegatedRng::get_mut`], without any overhead
+    /// per draw. Reseeding with [`GlobalRng::reseed`] or [`DelegatedRng::reseed`] keeps the
+    /// count, while restoring a [`GlobalRng`] from a saved state, such as by deserializing it,
+    /// starts counting from zero. Reseeding the internal [`Rng`] directly, such as with
+    /// `rng.get_mut().reseed(seed)`, makes the count meaningless.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = GlobalRng::with_seed(12345);
+    ///
+    /// for _ in 0..3 {
+    ///     rng.u64(..);
+    /// }
+    ///
+    /// assert_eq!(rng.draws(), 3);
+    /// ```
+    #[cfg(feature = "rng_stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rng_stats")))]
+    #[inline]
+    #[must_use]
+    pub fn draws(&self) -> u64 {
+        self.1.draws(&self.get_cell())
+    }
+
+    /// Encodes the exact internal state of the [`GlobalRng`] as 16 lowercase hex digits, for
+    /// dumping into logs and bug reports when diagnosing determinism issues. The state can
+    /// be restored with [`GlobalRng::from_state_hex`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut global = GlobalRng::with_seed(12345);
+    ///
+    /// let hex = global.state_hex();
+    ///
+    /// let mut restored = GlobalRng::from_state_hex(&hex).unwrap();
+    ///
+    /// assert_eq!(restored.u64(..), global.u64(..));
+    /// ```
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    #[must_use]
+    pub fn state_hex(&self) -> alloc::string::String {
+        crate::state::state_hex(&self.get_cell())
+    }
+
+    /// Creates a new [`GlobalRng`] instance from a hex string produced by
+    /// [`GlobalRng::state_hex`], restoring the exact internal state.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StateParseError`] if the string is not exactly 16 hex digits.
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    pub fn from_state_hex(hex: &str) -> Result<Self, StateParseError> {
+        crate::state::from_state_hex(hex).map(Self::from_rng)
+    }
+
+    /// Returns the raw internal state of the [`GlobalRng`] as a single `u64`, which can be
+    /// restored with [`GlobalRng::from_state`]. The WyRand state is exactly one `u64`, so this
+    /// is the most compact way of saving an RNG, at 8 bytes without any framing. Unlike
+    /// [`SerializableRngState`], the value carries no format version, so it relies on the
+    /// WyRand state layout staying the same.
+    ///
+    /// `turborand` only exposes the state through its `Serialize` implementation, which is
+    /// why this requires the `serialize` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = GlobalRng::with_seed(12345);
+    ///
+    /// let state = rng.state();
+    ///
+    /// let mut restored = GlobalRng::from_state(state);
+    ///
+    /// assert_eq!(restored.u64(..), rng.u64(..));
+    /// ```
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    #[must_use]
+    pub fn state(&self) -> u64 {
+        crate::state::rng_state(&self.get_cell())
+    }
+
+    /// Creates a new [`GlobalRng`] instance from a raw internal state returned by
+    /// [`GlobalRng::state`], continuing the exact sequence of the saved RNG.
+    #[cfg(feature = "serialize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+    #[inline]
+    #[must_use]
+    pub fn from_state(state: u64) -> Self {
+        Self::from_rng(crate::state::from_state(state))
+    }
+}
+
+impl fmt::Debug for GlobalRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GlobalRng").field(&*self.get_cell()).finish()
+    }
+}
+
+impl PartialEq for GlobalRng {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Comparing with itself would otherwise wait on its own handle forever
+        core::ptr::eq(self, other) || *self.get_cell() == *other.get_cell()
+    }
+}
+
+impl Eq for GlobalRng {}
+
+#[cfg(feature = "serialize")]
+crate::state::impl_newtype_serde!(GlobalRng, "GlobalRng", get_cell);
+
+#[cfg(feature = "serialize")]
+impl core::hash::Hash for GlobalRng {
+    /// Hashes the internal state of the [`GlobalRng`], consistent with its [`PartialEq`]
+    /// implementation.
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        crate::state::rng_state(&self.get_cell()).hash(state);
     }
 }
 
@@ -50,18 +429,22 @@ impl DelegatedRng for GlobalRng {
     /// ```
     #[inline]
     fn get_mut(&mut self) -> &mut Self::Source {
+        #[cfg(feature = "std")]
+        if self.3 {
+            crate::strict::assert_seeding();
+        }
+
         &mut self.0
     }
 
     #[inline]
-    fn weighted_sample_mut<'a, T, F>(
-        &'a mut self,
-        list: &'a mut [T],
-        weight_sampler: F,
-    ) -> Option<&'a mut T>
-    where
-        F: Fn(&T) -> f64 {
-        self.0.weighted_sample_mut(list, weight_sampler)
+    fn fork(&mut self) -> Self::Source {
+        self.0.fork()
+    }
+
+    #[inline]
+    fn reseed(&mut self, seed: u64) {
+        self.1.reseed(&mut self.0, seed);
     }
 }
 
@@ -80,3 +463,52 @@ impl AsMut<Rng> for GlobalRng {
         self.get_mut()
     }
 }
+
+/// Returns a non-zero token unique to the current thread among all running threads, for
+/// telling apart [`GlobalRng::get_cell`] handles held by this thread from other threads.
+/// Without `std` there are no other threads, so every handle is held by the same one.
+#[inline]
+fn thread_token() -> usize {
+    #[cfg(feature = "std")]
+    {
+        std::thread_local! {
+            static TOKEN: u8 = const { 0 };
+        }
+
+        TOKEN.with(|token| token as *const u8 as usize)
+    }
+
+    #[cfg(not(feature = "std"))]
+    {
+        1
+    }
+}
+
+/// A handle to the internal [`Rng`] of a [`GlobalRng`], obtained from a shared reference with
+/// [`GlobalRng::get_cell`]. Dereferences to the [`Rng`], and releases it once dropped.
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct GlobalRngCell<'a> {
+    global: &'a GlobalRng,
+}
+
+impl Deref for GlobalRngCell<'_> {
+    type Target = Rng;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.global.0
+    }
+}
+
+impl Drop for GlobalRngCell<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.global.2.store(0, Ordering::Release);
+    }
+}
+
+impl fmt::Debug for GlobalRngCell<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GlobalRngCell").field(&**self).finish()
+    }
+}
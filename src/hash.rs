@@ -0,0 +1,54 @@
+/// Golden ratio increment used by SplitMix64 to step through its sequence.
+pub(crate) const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// SplitMix64 finaliser. Takes any `u64` and scrambles it into a well distributed
+/// output, so that inputs that differ by only a few bits map onto very different seeds.
+#[inline]
+pub(crate) const fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(GOLDEN_GAMMA);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Combines a base seed with an additional value into a new seed, hashing both
+/// so that the result is deterministic but uncorrelated with the inputs.
+#[cfg(feature = "wyrand")]
+#[inline]
+pub(crate) const fn mix_seed(base_seed: u64, value: u64) -> u64 {
+    splitmix64(base_seed ^ splitmix64(value))
+}
+
+/// Returns the `index`th output of a SplitMix64 sequence starting from `base_seed`,
+/// computed directly so that outputs don't depend on generating any of the others.
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+#[inline]
+pub(crate) const fn nth_seed(base_seed: u64, index: u64) -> u64 {
+    splitmix64(base_seed.wrapping_add(index.wrapping_mul(GOLDEN_GAMMA)))
+}
+
+/// FNV-1a offset basis for 64-bit hashes.
+#[cfg(feature = "wyrand")]
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a prime for 64-bit hashes.
+#[cfg(feature = "wyrand")]
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Hashes bytes with 64-bit FNV-1a. Fully specified and implemented inline, so the
+/// output never changes across platforms, Rust versions or crate versions, unlike
+/// `DefaultHasher`.
+#[cfg(feature = "wyrand")]
+#[inline]
+pub(crate) const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        index += 1;
+    }
+
+    hash
+}
@@ -0,0 +1,99 @@
+use crate::hash::splitmix64;
+use core::hash::{BuildHasher, Hasher};
+
+/// A [`BuildHasher`] with a fixed seed, for building `HashMap`s and `HashSet`s whose
+/// hashing doesn't depend on the process-wide randomness of `RandomState`. Maps built with
+/// [`SeededState`]s of the same seed hash every key identically, and so iterate in the same
+/// order when given the same insertions, which keeps internal data structures reproducible.
+/// Usually obtained from [`DelegatedRng::seeded_hasher`], so the seed itself is derived
+/// deterministically from the RNG.
+///
+/// The hashing is based on SplitMix64, which is fast and well distributed, but is **not**
+/// resistant against collision attacks, so it should not be used for maps keyed by
+/// untrusted input.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+/// use std::collections::HashMap;
+///
+/// let mut rng = RngComponent::with_seed(12345);
+///
+/// let mut scores: HashMap<&str, u32, SeededState> = HashMap::with_hasher(rng.seeded_hasher());
+///
+/// scores.insert("alice", 10);
+///
+/// assert_eq!(scores.get("alice"), Some(&10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeededState {
+    seed: u64,
+}
+
+impl SeededState {
+    /// Create a new [`SeededState`] with a given seed.
+    #[inline]
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Returns the seed of the [`SeededState`].
+    #[inline]
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl BuildHasher for SeededState {
+    type Hasher = SeededHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        SeededHasher {
+            state: splitmix64(self.seed),
+        }
+    }
+}
+
+/// The [`Hasher`] created by a [`SeededState`].
+#[derive(Debug, Clone)]
+pub struct SeededHasher {
+    state: u64,
+}
+
+impl Hasher for SeededHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+
+            word.copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
+        }
+
+        let remainder = chunks.remainder();
+
+        if !remainder.is_empty() {
+            // Tagging the trailing word with its length keeps trailing zero bytes distinct
+            let mut word = [0; 8];
+
+            word[..remainder.len()].copy_from_slice(remainder);
+            word[7] = remainder.len() as u8;
+            self.write_u64(u64::from_le_bytes(word));
+        }
+    }
+
+    #[inline]
+    fn write_u64(&mut self, value: u64) {
+        self.state = splitmix64(self.state ^ value);
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        splitmix64(self.state)
+    }
+}
@@ -131,20 +131,61 @@
 //!
 //! Methods that are susceptible to this are [`TurboRand::usize`], [`TurboRand::sample`],
 //! [`TurboRand::sample_multiple`], [`TurboRand::weighted_sample`] and [`TurboRand::shuffle`].
+//! For results that are consistent across platforms, use [`DelegatedRng::shuffle_portable`],
+//! [`DelegatedRng::sample_portable`], [`DelegatedRng::sample_multiple_portable`] and
+//! [`DelegatedRng::weighted_sample_portable`] instead.
 //!
 //! # Features
 //!
-//! - **`wyrand`** - Enables [`GlobalRng`] & [`RngComponent`]. Is enabled by default.
+//! - **`std`** - Enables functionality that depends on the standard library, such as
+//!   seeding the [`RngPlugin`] from environment variables or the current time,
+//!   [`LocalRng`], [`RngPool`], normal distribution & weighted iterator sampling, and
+//!   [`std::error::Error`] impls.
+//!   Is enabled by default.
+//!   Without it, the crate is `#![no_std]` and only requires `alloc`, though note that
+//!   Bevy itself still depends on `std`.
+//! - **`wyrand`** - Enables [`GlobalRng`], [`RngComponent`], [`LocalRng`], [`SpawnRandomExt`],
+//!   [`RngCommandsExt`], [`RandomOnSpawnPlugin`], [`seed_from_parent`], [`RngStreams`],
+//!   [`QueryRngExt`] & [`RngAppExt`].
+//!   Is enabled by default.
 //!   Having this feature flag enabled also enables [`RngPlugin`].
 //! - **`chacha`** - Enables [`GlobalChaChaRng`] & [`ChaChaRngComponent`]. Having this
 //!   feature flag enabled also enables [`RngPlugin`].
 //! - **`rand`** - Provides [`RandBorrowed`], which implements `RngCore`
-//!   so to allow for compatibility with `rand` ecosystem of crates.
-//! - **`serialize`** - Enables [`Serialize`] and [`Deserialize`] derives.
+//!   so to allow for compatibility with `rand` ecosystem of crates, and can be obtained from
+//!   any [`DelegatedRng`] with [`DelegatedRng::as_rand`]. With `chacha` also
+//!   enabled, provides [`SecureRandBorrowed`], which additionally implements `CryptoRng`.
+//! - **`serialize`** - Enables [`Serialize`] and [`Deserialize`] derives, as well as
+//!   [`RngSnapshot`] for saving and restoring the state of all global RNG resources. With
+//!   `wyrand` also enabled, provides [`RngComponent::migrate`] for upgrading saved states
+//!   from older layouts, and with `std` as well, the [`RngDiagnosticsPlugin`] for debugging
+//!   determinism.
+//! - **`bevy_reflect`** - Enables [`Reflect`] & [`FromReflect`] implementations for
+//!   [`RngComponent`] & [`ChaChaRngComponent`], which then get registered by [`RngPlugin`]
+//!   for use with [`ReflectComponent`]. The components are reflected as opaque values, so
+//!   reflected clones keep the exact same internal state. Enables `std` as a result.
+//! - **`bevy_color`** - Enables [`DelegatedRng`] methods for generating random `Color` values.
+//!   Enables `std` & `bevy_render` as a result.
+//! - **`bevy_math`** - Enables [`DelegatedRng`] methods for generating random directions,
+//!   rotations and points within shapes with [`Vec2`], [`Vec3`] & [`Quat`]. Enables `std` as
+//!   a result.
+//! - **`atomic`** - Enables [`AtomicGlobalRng`], a thread-safe but non-deterministic
+//!   global RNG resource for drawing values in parallel from shared references. Enables
+//!   `wyrand` as a result.
+//! - **`rng_stats`** - Enables counting the draws made from each [`GlobalRng`] and
+//!   [`RngComponent`], such as with [`RngComponent::draws`]. Enables `wyrand` & `serialize` as
+//!   a result, as the draws are counted from the internal state.
+//! - **`derive`** - Provides a derive macro for [`RandomVariant`], for picking random
+//!   unit variants of enums.
+//! - **`testing`** - Provides the [`testing`] module, with helpers for writing determinism
+//!   tests. Enables `wyrand` as a result.
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs, rust_2018_idioms)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, allow(unused_attributes))]
 
+extern crate alloc;
+
 use bevy::prelude::*;
 #[cfg(any(feature = "chacha", feature = "wyrand"))]
 use turborand::prelude::*;
@@ -153,24 +194,96 @@ pub use turborand::{ForkableCore, GenCore, SecureCore, SeededCore, TurboCore, Tu
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "wyrand")]
+pub use app::*;
+#[cfg(feature = "wyrand")]
+pub use commands::*;
+#[cfg(all(feature = "rand", feature = "chacha"))]
+pub use compat::*;
 #[cfg(feature = "chacha")]
 pub use component::chacha::*;
 #[cfg(feature = "wyrand")]
 pub use component::rng::*;
+#[cfg(all(feature = "wyrand", feature = "serialize", feature = "std"))]
+pub use diagnostics::*;
+pub use dice::DiceParseError;
+#[cfg(any(feature = "wyrand", feature = "chacha"))]
+pub use events::*;
+#[cfg(feature = "atomic")]
+pub use global::atomic::*;
 #[cfg(feature = "chacha")]
 pub use global::chacha::*;
 #[cfg(feature = "wyrand")]
 pub use global::rng::*;
+pub use hasher::*;
+#[cfg(all(feature = "wyrand", feature = "std"))]
+pub use local::*;
+#[cfg(all(feature = "serialize", feature = "wyrand"))]
+pub use migrate::*;
+#[cfg(all(feature = "wyrand", feature = "std"))]
+pub use pool::*;
+#[cfg(feature = "wyrand")]
+pub use query::*;
+#[cfg(all(feature = "serialize", any(feature = "wyrand", feature = "chacha")))]
+pub use snapshot::*;
+#[cfg(feature = "wyrand")]
+pub use spawn::*;
+#[cfg(all(feature = "serialize", feature = "wyrand"))]
+pub use state::StateParseError;
+#[cfg(feature = "wyrand")]
+pub use streams::*;
+pub use table::*;
 pub use traits::*;
 
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use bevy_turborand_derive::RandomVariant;
+
 #[macro_use]
 mod delegate;
+#[cfg(feature = "wyrand")]
+mod app;
+#[cfg(feature = "wyrand")]
+mod commands;
+#[cfg(all(feature = "rand", feature = "chacha"))]
+mod compat;
 #[cfg(any(feature = "chacha", feature = "wyrand"))]
 mod component;
+#[cfg(all(feature = "wyrand", feature = "serialize", feature = "std"))]
+mod diagnostics;
+mod dice;
+#[cfg(feature = "std")]
+mod distributions;
+#[cfg(any(feature = "chacha", feature = "wyrand"))]
+mod events;
 #[cfg(any(feature = "chacha", feature = "wyrand"))]
 mod global;
+mod hash;
+mod hasher;
+#[cfg(all(feature = "wyrand", feature = "std"))]
+mod local;
+#[cfg(all(feature = "serialize", feature = "wyrand"))]
+mod migrate;
 #[cfg(any(feature = "wyrand", feature = "chacha"))]
 mod plugin;
+#[cfg(all(feature = "wyrand", feature = "std"))]
+mod pool;
+#[cfg(feature = "wyrand")]
+mod query;
+#[cfg(all(feature = "serialize", any(feature = "wyrand", feature = "chacha")))]
+mod snapshot;
+#[cfg(feature = "wyrand")]
+mod spawn;
+#[cfg(feature = "wyrand")]
+mod state;
+#[cfg(feature = "wyrand")]
+mod streams;
+#[cfg(feature = "wyrand")]
+mod strict;
+mod table;
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 mod traits;
 
 /// Prelude for `bevy_turborand`, exposing all necessary traits for default usage of the
@@ -218,4 +331,4 @@ pub mod rng {
     pub use turborand::prelude::*;
 }
 
-pub use plugin::RngPlugin;
+pub use plugin::{RngPlugin, RngSystem};
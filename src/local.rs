@@ -0,0 +1,61 @@
+use crate::*;
+use bevy::ecs::system::SystemParam;
+
+/// A [`SystemParam`] that provides a per-system [`RngComponent`], for systems that
+/// need a scratch RNG but don't belong to any specific entity.
+///
+/// The [`RngComponent`] is created lazily on first access, seeded from the [`GlobalRng`]
+/// resource, and then cached in a [`Local`] so its sequence persists across runs of the
+/// same system.
+///
+/// # Determinism
+///
+/// Each [`LocalRng`] is seeded from a single draw of the [`GlobalRng`], so its
+/// sequence is deterministic as long as the [`GlobalRng`] is seeded and the system
+/// that owns the [`LocalRng`] first accesses it in a stable order relative to other
+/// systems drawing from the [`GlobalRng`]. After the first access, the [`LocalRng`] no
+/// longer draws from the [`GlobalRng`], and only depends on how often its own system
+/// uses it. As it holds a `ResMut<GlobalRng>` in order to lazily seed itself, systems
+/// using [`LocalRng`] will not run in parallel with other systems accessing the
+/// [`GlobalRng`], which allows Bevy's ambiguity checker to point out unordered ones.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_particles(mut commands: Commands, mut rng: LocalRng) {
+///     let amount = rng.u32(1..=5);
+///
+///     for _ in 0..amount {
+///         commands.spawn(Transform::from_xyz(rng.f32(), rng.f32(), 0.0));
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(spawn_particles);
+/// ```
+#[derive(SystemParam)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+pub struct LocalRng<'w, 's> {
+    rng: Local<'s, Option<RngComponent>>,
+    global: ResMut<'w, GlobalRng>,
+}
+
+impl LocalRng<'_, '_> {
+    /// Returns the [`RngComponent`] owned by this system, seeding it from the
+    /// [`GlobalRng`] if this is the first time it is being accessed.
+    #[inline]
+    pub fn component(&mut self) -> &mut RngComponent {
+        let global = &mut self.global;
+
+        self.rng.get_or_insert_with(|| RngComponent::from(global))
+    }
+}
+
+impl DelegatedRng for LocalRng<'_, '_> {
+    type Source = Rng;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        self.component().get_mut()
+    }
+}
@@ -0,0 +1,136 @@
+use crate::*;
+use core::fmt::{self, Display};
+
+/// The binary layouts of [`RngComponent`] states that [`RngComponent::migrate`] knows how to
+/// detect and upgrade, for loading save files written by older versions of this crate or of
+/// `turborand`, whose internal state layout could change between major versions.
+///
+/// All layouts are described as encoded by a compact binary format using fixed-width,
+/// little-endian integers, such as `bincode`'s default configuration. Every layout is
+/// currently told apart by its length alone. Whenever a version of `turborand` changes the
+/// layout of its state, the previous layout gains a variant here, so saves keep loading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serialize", feature = "wyrand"))))]
+pub enum LegacyRngState {
+    /// The layout of a serialized [`RngComponent`] or [`GlobalRng`] with `turborand` 0.8,
+    /// which is only the 8 bytes of the internal WyRand state, as described by
+    /// [`RngComponent::SERIALIZED_SIZE`].
+    Turborand08 {
+        /// The raw internal WyRand state.
+        state: u64,
+    },
+    /// The layout of a serialized [`SerializableRngState`], which is the 4 bytes of its format
+    /// version followed by the 8 bytes of the internal WyRand state.
+    Versioned {
+        /// The [`SerializableRngState`] format version.
+        version: u32,
+        /// The raw internal WyRand state.
+        state: u64,
+    },
+}
+
+impl LegacyRngState {
+    /// Detects the layout of the given bytes, without upgrading it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigrateError::UnknownLayout`] if the bytes don't match any known layout.
+    pub fn detect(bytes: &[u8]) -> Result<Self, MigrateError> {
+        match bytes.len() {
+            8 => Ok(Self::Turborand08 {
+                state: u64::from_le_bytes(to_array(bytes)),
+            }),
+            12 => Ok(Self::Versioned {
+                version: u32::from_le_bytes(to_array(&bytes[..4])),
+                state: u64::from_le_bytes(to_array(&bytes[4..])),
+            }),
+            len => Err(MigrateError::UnknownLayout(len)),
+        }
+    }
+}
+
+#[inline]
+fn to_array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    bytes
+        .try_into()
+        .expect("the length should have been checked beforehand")
+}
+
+impl TryFrom<LegacyRngState> for RngComponent {
+    type Error = MigrateError;
+
+    #[inline]
+    fn try_from(legacy: LegacyRngState) -> Result<Self, Self::Error> {
+        match legacy {
+            LegacyRngState::Turborand08 { state } => {
+                Ok(Self::from_rng(crate::state::from_state(state)))
+            }
+            LegacyRngState::Versioned { version, state } => {
+                Self::try_from(SerializableRngState::from_parts(version, state)).map_err(|error| {
+                    match error {
+                        SerializableRngStateError::UnsupportedVersion(version) => {
+                            MigrateError::UnsupportedVersion(version)
+                        }
+                    }
+                })
+            }
+        }
+    }
+}
+
+impl RngComponent {
+    /// Creates a new [`RngComponent`] from bytes in any of the known [`LegacyRngState`]
+    /// layouts, detecting the layout and upgrading it to the current one. The restored
+    /// [`RngComponent`] continues the exact sequence of the one that was saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MigrateError::UnknownLayout`] if the bytes don't match any known layout, or
+    /// [`MigrateError::UnsupportedVersion`] if they are a [`SerializableRngState`] with a
+    /// format version that has no migration path.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// // An `RngComponent::with_seed(12345)` saved with `turborand` 0.8
+    /// let saved = [0x73, 0x60, 0, 0, 0, 0, 0, 0];
+    ///
+    /// let mut migrated = RngComponent::migrate(&saved).unwrap();
+    ///
+    /// assert_eq!(migrated.u64(..), RngComponent::with_seed(12345).u64(..));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "serialize", feature = "wyrand"))))]
+    pub fn migrate(bytes: &[u8]) -> Result<Self, MigrateError> {
+        LegacyRngState::detect(bytes)?.try_into()
+    }
+}
+
+/// Errors that can occur when migrating a [`LegacyRngState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serialize", feature = "wyrand"))))]
+pub enum MigrateError {
+    /// The bytes did not match any known layout. Contains the length received.
+    UnknownLayout(usize),
+    /// The state was created with a format version that has no migration path.
+    UnsupportedVersion(u32),
+}
+
+impl Display for MigrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownLayout(len) => {
+                write!(f, "no known RNG state layout is {} bytes long", len)
+            }
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported SerializableRngState version {}, expected {} or older",
+                version,
+                SerializableRngState::VERSION
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MigrateError {}
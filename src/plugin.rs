@@ -1,4 +1,6 @@
 use crate::*;
+#[cfg(feature = "chacha")]
+use core::time::Duration;
 
 /// A [`Plugin`] for initialising a [`GlobalRng`] & [`GlobalChaChaRng`]
 /// (if the feature flags are enabled for either of them) into a Bevy `App`.
@@ -18,9 +20,48 @@ pub struct RngPlugin {
     #[cfg(feature = "wyrand")]
     #[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
     rng: Option<u64>,
+    #[cfg(all(feature = "wyrand", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+    rng_env: Option<String>,
+    #[cfg(all(feature = "wyrand", feature = "std", not(target_arch = "wasm32")))]
+    rng_time: bool,
+    #[cfg(all(feature = "wyrand", feature = "std"))]
+    strict_global: bool,
     #[cfg(feature = "chacha")]
     #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
     chacha: Option<[u8; 40]>,
+    #[cfg(feature = "chacha")]
+    chacha_reseed_interval: Option<Duration>,
+}
+
+/// [`SystemLabel`]s for the systems added by `bevy_turborand`, so that user systems can
+/// be ordered against them.
+///
+/// Systems labelled with [`RngSystem::Reseed`] handle [`ReseedGlobalRng`] &
+/// [`ReseedGlobalChaChaRng`] events in [`CoreStage::First`], as well as the state
+/// transition reseeds added with `RngAppExt::reseed_on_enter`. A system in the same stage
+/// ordered with `.after(RngSystem::Reseed)` is guaranteed to observe the reseeded state.
+/// Systems in later stages always run after the event driven reseeds.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_level(mut global: ResMut<GlobalRng>) {
+///     let _layout = global.u64(..);
+/// }
+///
+/// App::new()
+///     .add_plugin(RngPlugin::new())
+///     .add_system_to_stage(CoreStage::First, spawn_level.after(RngSystem::Reseed))
+///     .run();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemLabel)]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "wyrand", feature = "chacha"))))]
+pub enum RngSystem {
+    /// Systems that reseed the global RNG resources.
+    Reseed,
 }
 
 impl RngPlugin {
@@ -32,8 +73,16 @@ impl RngPlugin {
         Self {
             #[cfg(feature = "wyrand")]
             rng: None,
+            #[cfg(all(feature = "wyrand", feature = "std"))]
+            rng_env: None,
+            #[cfg(all(feature = "wyrand", feature = "std", not(target_arch = "wasm32")))]
+            rng_time: false,
+            #[cfg(all(feature = "wyrand", feature = "std"))]
+            strict_global: false,
             #[cfg(feature = "chacha")]
             chacha: None,
+            #[cfg(feature = "chacha")]
+            chacha_reseed_interval: None,
         }
     }
 
@@ -47,6 +96,145 @@ impl RngPlugin {
         self
     }
 
+    /// Builder function to read the seed value for a [`GlobalRng`] from an environment
+    /// variable, which is parsed as a `u64` when the plugin is built. Useful for reproducing
+    /// sessions from the command line, such as `BEVY_TURBORAND_SEED=12345 cargo run`.
+    ///
+    /// An explicit seed provided via [`RngPlugin::with_rng_seed`] takes precedence. If the
+    /// variable is missing, the [`GlobalRng`] will be initialised with a randomised seed. The
+    /// same happens if the variable can't be parsed, though a warning is logged as well.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// App::new()
+    ///     .add_plugin(RngPlugin::new().with_seed_from_env("BEVY_TURBORAND_SEED"))
+    ///     .run();
+    /// ```
+    #[cfg(all(feature = "wyrand", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+    #[inline]
+    #[must_use]
+    pub fn with_seed_from_env(mut self, var_name: &str) -> Self {
+        self.rng_env = Some(var_name.to_owned());
+        self
+    }
+
+    /// Builder function to seed the [`GlobalRng`] from the current Unix timestamp in
+    /// nanoseconds when the plugin is built, so it differs on every launch. The chosen seed
+    /// is logged with [`info!`], so that a session can be reproduced afterwards by passing it
+    /// to [`RngPlugin::with_rng_seed`].
+    ///
+    /// Seeds provided via [`RngPlugin::with_rng_seed`] or [`RngPlugin::with_seed_from_env`]
+    /// take precedence. Not available on `wasm32`, where the system clock can't be read
+    /// through `std`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// App::new()
+    ///     .add_plugin(RngPlugin::new().with_time_seed())
+    ///     .run();
+    /// ```
+    #[cfg(all(feature = "wyrand", feature = "std", not(target_arch = "wasm32")))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(feature = "wyrand", feature = "std", not(target_arch = "wasm32"))))
+    )]
+    #[inline]
+    #[must_use]
+    pub const fn with_time_seed(mut self) -> Self {
+        self.rng_time = true;
+        self
+    }
+
+    /// Builder function to put the [`GlobalRng`] in strict mode, so that drawing from it
+    /// directly panics, rather than only using it for seeding [`RngComponent`]s. Helps with
+    /// enforcing the practice of giving every entity its own [`RngComponent`], as gameplay
+    /// systems drawing from the [`GlobalRng`] lose determinism as soon as the order of
+    /// systems or entities changes. See [`GlobalRng::set_strict`] for which uses are allowed.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn spawn_enemy(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    ///     // Allowed, as the `GlobalRng` only seeds the new component
+    ///     commands.spawn(RngComponent::from(&mut global));
+    ///
+    ///     // Would panic, as this draws from the `GlobalRng` directly
+    ///     // let health = global.u32(10..=20);
+    /// }
+    ///
+    /// App::new()
+    ///     .add_plugin(RngPlugin::new().strict_global())
+    ///     .add_startup_system(spawn_enemy)
+    ///     .run();
+    /// ```
+    #[cfg(all(feature = "wyrand", feature = "std"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+    #[inline]
+    #[must_use]
+    pub const fn strict_global(mut self) -> Self {
+        self.strict_global = true;
+        self
+    }
+
+    /// Builder function to derive the seeds for both the [`GlobalRng`] & [`GlobalChaChaRng`]
+    /// (if the feature flags are enabled for either of them) from a single master seed, so
+    /// that a whole session can be reproduced by sharing one number. Overrides any seeds
+    /// set previously, while seeds set afterwards take precedence.
+    ///
+    /// The seeds are expanded from the master seed with a SplitMix64 sequence, whose outputs
+    /// are computed as `splitmix64(master_seed + index * 0x9e3779b97f4a7c15)`. The first
+    /// output (index `0`) seeds the [`GlobalRng`], while the next five (indices `1` to `5`)
+    /// are concatenated as little-endian bytes into the 40 byte [`GlobalChaChaRng`] seed.
+    /// The two seeds are therefore uncorrelated, and the secure seed can't be recovered
+    /// from the [`GlobalRng`] output.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// App::new()
+    ///     .add_plugin(RngPlugin::new().with_master_seed(20221201))
+    ///     .run();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_master_seed(mut self, seed: u64) -> Self {
+        #[cfg(feature = "wyrand")]
+        {
+            self.rng = Some(crate::hash::nth_seed(seed, 0));
+        }
+        #[cfg(feature = "chacha")]
+        {
+            let mut bytes = [0; 40];
+            let mut index = 0;
+
+            while index < 5 {
+                let word = crate::hash::nth_seed(seed, index as u64 + 1).to_le_bytes();
+                let mut byte = 0;
+
+                while byte < 8 {
+                    bytes[index * 8 + byte] = word[byte];
+                    byte += 1;
+                }
+
+                index += 1;
+            }
+
+            self.chacha = Some(bytes);
+        }
+        self
+    }
+
     /// Builder function to set a seed value for a [`GlobalChaChaRng`].
     #[cfg(feature = "chacha")]
     #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
@@ -56,6 +244,38 @@ impl RngPlugin {
         self.chacha = Some(seed);
         self
     }
+
+    /// Builder function to periodically reseed the [`GlobalChaChaRng`] with fresh entropy
+    /// from the OS, once every `interval` as measured by Bevy's [`Time`] resource. Limits
+    /// how long any single keystream is in use, such as for long-running servers handing
+    /// out session tokens.
+    ///
+    /// This intentionally breaks the determinism of the [`GlobalChaChaRng`], even if it was
+    /// seeded with [`RngPlugin::with_chacha_seed`] or [`RngPlugin::with_master_seed`], as
+    /// the seed only applies until the first reseed. The [`GlobalRng`] is unaffected. The
+    /// reseeding system runs in [`CoreStage::First`] labelled with [`RngSystem::Reseed`],
+    /// and requires the [`Time`] resource, which is provided by Bevy's `TimePlugin`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    /// use bevy::time::TimePlugin;
+    /// use std::time::Duration;
+    ///
+    /// App::new()
+    ///     .add_plugin(TimePlugin)
+    ///     .add_plugin(RngPlugin::new().with_secure_reseed_interval(Duration::from_secs(3600)))
+    ///     .run();
+    /// ```
+    #[cfg(feature = "chacha")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chacha")))]
+    #[inline]
+    #[must_use]
+    pub const fn with_secure_reseed_interval(mut self, interval: Duration) -> Self {
+        self.chacha_reseed_interval = Some(interval);
+        self
+    }
 }
 
 impl Default for RngPlugin {
@@ -70,12 +290,98 @@ impl Default for RngPlugin {
 
 impl Plugin for RngPlugin {
     fn build(&self, app: &mut App) {
-        #[cfg(feature = "wyrand")]
+        #[cfg(all(feature = "wyrand", feature = "bevy_reflect"))]
+        app.register_type::<RngComponent>();
+        #[cfg(all(feature = "chacha", feature = "bevy_reflect"))]
+        app.register_type::<ChaChaRngComponent>();
+        #[cfg(all(feature = "wyrand", feature = "std"))]
+        {
+            let seed = self
+                .rng
+                .or_else(|| self.rng_env.as_deref().and_then(seed_from_env));
+            #[cfg(not(target_arch = "wasm32"))]
+            let seed = seed.or_else(|| self.rng_time.then(seed_from_time));
+
+            let mut global = seed.map_or_else(GlobalRng::new, GlobalRng::with_seed);
+
+            global.set_strict(self.strict_global);
+
+            app.insert_resource(global);
+        }
+        #[cfg(all(feature = "wyrand", not(feature = "std")))]
         app.insert_resource(self.rng.map_or_else(GlobalRng::new, GlobalRng::with_seed));
+        #[cfg(feature = "atomic")]
+        app.init_resource::<AtomicGlobalRng>();
         #[cfg(feature = "chacha")]
         app.insert_resource(
             self.chacha
                 .map_or_else(GlobalChaChaRng::new, GlobalChaChaRng::with_seed),
         );
+
+        #[cfg(feature = "wyrand")]
+        app.add_event::<ReseedGlobalRng>().add_system_to_stage(
+            CoreStage::First,
+            crate::events::reseed_global_rng.label(RngSystem::Reseed),
+        );
+        #[cfg(feature = "chacha")]
+        app.add_event::<ReseedGlobalChaChaRng>()
+            .add_system_to_stage(
+                CoreStage::First,
+                crate::events::reseed_global_chacha_rng.label(RngSystem::Reseed),
+            );
+        #[cfg(feature = "chacha")]
+        if let Some(interval) = self.chacha_reseed_interval {
+            app.insert_resource(crate::events::SecureReseedTimer(Timer::new(
+                interval,
+                TimerMode::Repeating,
+            )))
+            .add_system_to_stage(
+                CoreStage::First,
+                crate::events::reseed_global_chacha_rng_on_interval.label(RngSystem::Reseed),
+            );
+        }
     }
 }
+
+/// Reads and parses a `u64` seed from the given environment variable, logging a
+/// warning if the variable is present but can't be parsed.
+#[cfg(all(feature = "wyrand", feature = "std"))]
+fn seed_from_env(var_name: &str) -> Option<u64> {
+    match std::env::var(var_name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .map_err(|_| {
+                warn!(
+                    "Unable to parse `{}` value `{}` as a u64 seed, using a randomised seed instead",
+                    var_name, value
+                );
+            })
+            .ok(),
+        Err(std::env::VarError::NotUnicode(_)) => {
+            warn!(
+                "`{}` is not valid unicode, using a randomised seed instead",
+                var_name
+            );
+            None
+        }
+        Err(std::env::VarError::NotPresent) => None,
+    }
+}
+
+/// Derives a seed from the current Unix timestamp in nanoseconds, logging it so that
+/// it can be reused with [`RngPlugin::with_rng_seed`].
+#[cfg(all(feature = "wyrand", feature = "std", not(target_arch = "wasm32")))]
+fn seed_from_time() -> u64 {
+    let seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos() as u64;
+
+    info!(
+        "Seeded GlobalRng from the current time with {}, reproduce with `RngPlugin::with_rng_seed({})`",
+        seed, seed
+    );
+
+    seed
+}
@@ -0,0 +1,134 @@
+use crate::*;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A pool of reusable scratch [`Rng`] sources, for hot systems that need short-lived
+/// generators without paying for a freshly seeded [`Rng`] each time.
+///
+/// Generators are handed out as [`PooledRng`]s via [`RngPool::acquire`], which return
+/// themselves to the pool once dropped. New generators are only created when the pool
+/// has none available, and are forked from a master [`Rng`], so the sequences handed out
+/// are deterministic as long as the pool is seeded and generators are acquired and
+/// dropped in a stable order.
+///
+/// [`RngPool`] can be inserted as a [`Resource`], or used as a [`Local`], in which case
+/// it is seeded from the [`GlobalRng`] resource if present, or with a randomised seed
+/// otherwise.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn scatter_debris(mut pool: Local<RngPool>, q_explosions: Query<&Transform>) {
+///     for transform in q_explosions.iter() {
+///         let mut rng = pool.acquire();
+///
+///         let offset = Vec3::new(rng.f32_normalized(), rng.f32_normalized(), 0.0);
+///
+///         println!("Debris at {:?}", transform.translation + offset);
+///     }
+/// }
+/// # bevy::ecs::system::assert_is_system(scatter_debris);
+/// ```
+#[derive(Debug, Resource)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+pub struct RngPool {
+    master: Rng,
+    free: Arc<Mutex<Vec<Rng>>>,
+}
+
+unsafe impl Sync for RngPool {}
+
+impl RngPool {
+    /// Create a new [`RngPool`] with a randomised master seed.
+    // No `Default`, as it would conflict with the `FromWorld` impl seeding from `GlobalRng`.
+    #[allow(clippy::new_without_default)]
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_rng(Rng::new())
+    }
+
+    /// Create a new [`RngPool`] with a given master seed.
+    #[inline]
+    #[must_use]
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_rng(Rng::with_seed(seed))
+    }
+
+    #[inline]
+    fn from_rng(master: Rng) -> Self {
+        Self {
+            master,
+            free: Arc::default(),
+        }
+    }
+
+    /// Acquires a [`PooledRng`] from the pool, reusing a previously released generator if
+    /// one is available, otherwise forking a new one from the master [`Rng`].
+    #[must_use]
+    pub fn acquire(&mut self) -> PooledRng {
+        let recycled = self
+            .free
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop();
+
+        PooledRng {
+            rng: Some(recycled.unwrap_or_else(|| self.master.fork())),
+            pool: Arc::clone(&self.free),
+        }
+    }
+
+    /// Returns the number of generators currently held by the pool, ready to be reused.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.free
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+impl FromWorld for RngPool {
+    /// Creates an [`RngPool`] seeded from the [`GlobalRng`] resource if present,
+    /// otherwise with a randomised master seed.
+    fn from_world(world: &mut World) -> Self {
+        world
+            .get_resource_mut::<GlobalRng>()
+            .map_or_else(Self::new, |mut global| Self::from_rng(global.fork()))
+    }
+}
+
+/// A scratch [`Rng`] source acquired from an [`RngPool`], which returns itself to
+/// the pool when dropped. Can outlive the borrow of the [`RngPool`] it was acquired from.
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "wyrand", feature = "std"))))]
+pub struct PooledRng {
+    rng: Option<Rng>,
+    pool: Arc<Mutex<Vec<Rng>>>,
+}
+
+unsafe impl Sync for PooledRng {}
+
+impl DelegatedRng for PooledRng {
+    type Source = Rng;
+
+    #[inline]
+    fn get_mut(&mut self) -> &mut Self::Source {
+        self.rng
+            .as_mut()
+            .expect("a PooledRng should hold its Rng until dropped")
+    }
+}
+
+impl Drop for PooledRng {
+    fn drop(&mut self) {
+        if let Some(rng) = self.rng.take() {
+            self.pool
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(rng);
+        }
+    }
+}
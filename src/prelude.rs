@@ -6,17 +6,59 @@ pub use turborand::prelude::Rng;
 #[cfg(feature = "chacha")]
 pub use turborand::prelude::ChaChaRng;
 
+#[cfg(feature = "atomic")]
+pub use turborand::prelude::AtomicRng;
+
 #[cfg(feature = "rand")]
 pub use turborand::prelude::RandBorrowed;
 
+#[cfg(feature = "wyrand")]
+pub use crate::app::RngAppExt;
+#[cfg(feature = "wyrand")]
+pub use crate::commands::{RngCommandsExt, SpawnRandomExt};
+#[cfg(all(feature = "rand", feature = "chacha"))]
+pub use crate::compat::SecureRandBorrowed;
 #[cfg(feature = "chacha")]
 pub use crate::component::chacha::ChaChaRngComponent;
 #[cfg(feature = "wyrand")]
 pub use crate::component::rng::RngComponent;
+#[cfg(all(feature = "wyrand", feature = "serialize", feature = "std"))]
+pub use crate::diagnostics::{RngDiagnosticsPlugin, RngDrawCount};
+pub use crate::dice::DiceParseError;
+#[cfg(feature = "chacha")]
+pub use crate::events::ReseedGlobalChaChaRng;
+#[cfg(feature = "wyrand")]
+pub use crate::events::ReseedGlobalRng;
+#[cfg(feature = "atomic")]
+pub use crate::global::atomic::AtomicGlobalRng;
 #[cfg(feature = "chacha")]
-pub use crate::global::chacha::GlobalChaChaRng;
+pub use crate::global::chacha::{GlobalChaChaRng, WeakSeedError};
 #[cfg(feature = "wyrand")]
 pub use crate::global::rng::GlobalRng;
+pub use crate::hasher::{SeededHasher, SeededState};
+#[cfg(all(feature = "wyrand", feature = "std"))]
+pub use crate::local::LocalRng;
+#[cfg(all(feature = "serialize", feature = "wyrand"))]
+pub use crate::migrate::{LegacyRngState, MigrateError};
 #[cfg(any(feature = "wyrand", feature = "chacha"))]
-pub use crate::plugin::RngPlugin;
-pub use crate::traits::DelegatedRng;
+pub use crate::plugin::{RngPlugin, RngSystem};
+#[cfg(all(feature = "wyrand", feature = "std"))]
+pub use crate::pool::{PooledRng, RngPool};
+#[cfg(feature = "wyrand")]
+pub use crate::query::QueryRngExt;
+#[cfg(all(feature = "serialize", any(feature = "wyrand", feature = "chacha")))]
+pub use crate::snapshot::{RngSnapshot, RngSnapshotError};
+#[cfg(all(feature = "serialize", feature = "wyrand"))]
+pub use crate::snapshot::{SerializableRngState, SerializableRngStateError};
+#[cfg(feature = "wyrand")]
+pub use crate::spawn::{seed_from_parent, NeedsRng, RandomOnSpawnPlugin};
+#[cfg(all(feature = "serialize", feature = "wyrand"))]
+pub use crate::state::StateParseError;
+#[cfg(feature = "wyrand")]
+pub use crate::streams::RngStreams;
+pub use crate::table::{WeightedTable, WeightedTableError};
+pub use crate::traits::{
+    apply_permutation, DelegatedRng, RandomVariant, SampleFromRng, SampleInteger,
+};
+#[cfg(feature = "derive")]
+pub use bevy_turborand_derive::RandomVariant;
@@ -0,0 +1,47 @@
+use crate::*;
+use bevy::ecs::query::{ReadOnlyWorldQuery, WorldQuery};
+
+/// An extension trait for [`Query`]s over some data alongside an [`RngComponent`], for
+/// drawing one random value per matched entity without having to juggle the borrows of the
+/// data and the [`RngComponent`] separately.
+///
+/// Each value is drawn from the entity's own [`RngComponent`], so with seeded components
+/// every entity always gets the same value, regardless of the order entities are iterated.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Component)]
+/// struct Flicker(f64);
+///
+/// fn flicker_torches(mut q_torches: Query<(&mut Flicker, &mut RngComponent)>) {
+///     q_torches.for_each_with_rng(|mut flicker, value| {
+///         flicker.0 = 0.8 + value * 0.2;
+///     });
+/// }
+/// # bevy::ecs::system::assert_is_system(flicker_torches);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub trait QueryRngExt<'a, D: WorldQuery> {
+    /// Calls the closure once for every matched entity, with the entity's data and a random
+    /// `f64` value within `0.0..1.0`, drawn from the entity's [`RngComponent`] beforehand
+    /// with [`TurboRand::f64`].
+    fn for_each_with_rng(&'a mut self, f: impl FnMut(D::Item<'a>, f64));
+}
+
+impl<'w, 's, 'a, D, F> QueryRngExt<'a, D> for Query<'w, 's, (D, &mut RngComponent), F>
+where
+    D: WorldQuery,
+    F: ReadOnlyWorldQuery,
+{
+    #[inline]
+    fn for_each_with_rng(&'a mut self, mut f: impl FnMut(D::Item<'a>, f64)) {
+        self.for_each_mut(|(data, mut rng)| {
+            let value = rng.f64();
+
+            f(data, value);
+        });
+    }
+}
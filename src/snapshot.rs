@@ -0,0 +1,262 @@
+use crate::*;
+use core::fmt::{self, Display};
+
+/// A snapshot of the state of all global RNG resources in a [`World`], for persisting
+/// the exact RNG state alongside the rest of a save file. Captures [`GlobalRng`] and
+/// [`GlobalChaChaRng`] (if the feature flags are enabled for either of them), along with
+/// a version tag to identify the format of the snapshot.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// let mut world = World::new();
+///
+/// world.insert_resource(GlobalRng::with_seed(12345));
+///
+/// let snapshot = RngSnapshot::capture(&world);
+///
+/// let expected = world.resource_mut::<GlobalRng>().u64(..);
+///
+/// snapshot.restore(&mut world).unwrap();
+///
+/// assert_eq!(world.resource_mut::<GlobalRng>().u64(..), expected);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub struct RngSnapshot {
+    version: u32,
+    #[cfg(feature = "wyrand")]
+    #[serde(default)]
+    rng: Option<Rng>,
+    #[cfg(feature = "chacha")]
+    #[serde(default)]
+    chacha: Option<ChaChaRng>,
+}
+
+impl RngSnapshot {
+    /// The current version of the [`RngSnapshot`] format.
+    pub const VERSION: u32 = 1;
+
+    /// Captures the state of the global RNG resources in the given [`World`]. Resources
+    /// that are not present in the [`World`] are left out of the snapshot.
+    #[must_use]
+    pub fn capture(world: &World) -> Self {
+        Self {
+            version: Self::VERSION,
+            #[cfg(feature = "wyrand")]
+            rng: world
+                .get_resource::<GlobalRng>()
+                .map(|rng| rng.get_cell().clone()),
+            #[cfg(feature = "chacha")]
+            chacha: world
+                .get_resource::<GlobalChaChaRng>()
+                .map(|rng| rng.0.clone()),
+        }
+    }
+
+    /// Restores the state of the global RNG resources in the given [`World`] from the
+    /// snapshot, inserting them if they are not present. Resources that were not captured
+    /// in the snapshot are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RngSnapshotError::UnsupportedVersion`] if the snapshot was created with a
+    /// format version that is not supported, in which case the [`World`] is not modified.
+    pub fn restore(&self, world: &mut World) -> Result<(), RngSnapshotError> {
+        if self.version != Self::VERSION {
+            return Err(RngSnapshotError::UnsupportedVersion(self.version));
+        }
+
+        #[cfg(feature = "wyrand")]
+        if let Some(rng) = &self.rng {
+            world.insert_resource(GlobalRng::from_rng(rng.clone()));
+        }
+
+        #[cfg(feature = "chacha")]
+        if let Some(rng) = &self.chacha {
+            world.insert_resource(GlobalChaChaRng(rng.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the format version of the snapshot.
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+/// Errors that can occur when restoring an [`RngSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serialize")))]
+pub enum RngSnapshotError {
+    /// The snapshot was created with an unsupported format version.
+    UnsupportedVersion(u32),
+}
+
+impl Display for RngSnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported RngSnapshot version {}, expected {}",
+                version,
+                RngSnapshot::VERSION
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RngSnapshotError {}
+
+/// A serializable representation of the state of a [`GlobalRng`] or [`RngComponent`], with
+/// an explicit format version for save file stability.
+///
+/// The derived [`Serialize`] implementation of [`Rng`] comes from `turborand`, so its
+/// format could change between `turborand` versions and break existing saves. Instead,
+/// [`SerializableRngState`] stores the raw WyRand state under a format that this crate
+/// controls, along with a `version` field identifying it. Whenever the format changes, the
+/// version is bumped and [`SerializableRngState::migrate`] gains a step for upgrading the
+/// previous versions, so old saves keep loading as long as a migration path exists.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut global = GlobalRng::with_seed(12345);
+///
+/// let state = SerializableRngState::from(&global);
+///
+/// let expected = global.u64(..);
+///
+/// let mut restored = GlobalRng::try_from(state).unwrap();
+///
+/// assert_eq!(restored.u64(..), expected);
+/// ```
+#[cfg(feature = "wyrand")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serialize", feature = "wyrand"))))]
+pub struct SerializableRngState {
+    version: u32,
+    state: u64,
+}
+
+#[cfg(feature = "wyrand")]
+impl SerializableRngState {
+    /// The current version of the [`SerializableRngState`] format.
+    pub const VERSION: u32 = 1;
+
+    /// Returns the format version of the state.
+    #[inline]
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Upgrades a state created with an older format version to the current
+    /// [`SerializableRngState::VERSION`]. This is the hook where future format changes
+    /// convert older versions, one step at a time. It gets called automatically when
+    /// converting into a [`GlobalRng`] or [`RngComponent`], so it only needs to be called
+    /// directly for re-saving upgraded states.
+    ///
+    /// As there is only one version of the format so far, states with the current version
+    /// are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SerializableRngStateError::UnsupportedVersion`] if there is no migration path from
+    /// the state's version, such as for states created by a newer version of this crate.
+    pub fn migrate(self) -> Result<Self, SerializableRngStateError> {
+        match self.version {
+            Self::VERSION => Ok(self),
+            version => Err(SerializableRngStateError::UnsupportedVersion(version)),
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_parts(version: u32, state: u64) -> Self {
+        Self { version, state }
+    }
+
+    #[inline]
+    fn capture(rng: &Rng) -> Self {
+        Self {
+            version: Self::VERSION,
+            state: crate::state::rng_state(rng),
+        }
+    }
+
+    #[inline]
+    fn restore(self) -> Result<Rng, SerializableRngStateError> {
+        let migrated = self.migrate()?;
+
+        Ok(crate::state::from_state(migrated.state))
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl From<&GlobalRng> for SerializableRngState {
+    #[inline]
+    fn from(global: &GlobalRng) -> Self {
+        Self::capture(&global.get_cell())
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl From<&RngComponent> for SerializableRngState {
+    #[inline]
+    fn from(component: &RngComponent) -> Self {
+        Self::capture(&component.0)
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl TryFrom<SerializableRngState> for GlobalRng {
+    type Error = SerializableRngStateError;
+
+    #[inline]
+    fn try_from(state: SerializableRngState) -> Result<Self, Self::Error> {
+        state.restore().map(Self::from_rng)
+    }
+}
+
+#[cfg(feature = "wyrand")]
+impl TryFrom<SerializableRngState> for RngComponent {
+    type Error = SerializableRngStateError;
+
+    #[inline]
+    fn try_from(state: SerializableRngState) -> Result<Self, Self::Error> {
+        state.restore().map(Self::from_rng)
+    }
+}
+
+/// Errors that can occur when restoring a [`SerializableRngState`].
+#[cfg(feature = "wyrand")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serialize", feature = "wyrand"))))]
+pub enum SerializableRngStateError {
+    /// The state was created with a format version that has no migration path.
+    UnsupportedVersion(u32),
+}
+
+#[cfg(feature = "wyrand")]
+impl Display for SerializableRngStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported SerializableRngState version {}, expected {} or older",
+                version,
+                SerializableRngState::VERSION
+            ),
+        }
+    }
+}
+
+#[cfg(all(feature = "wyrand", feature = "std"))]
+impl std::error::Error for SerializableRngStateError {}
@@ -0,0 +1,135 @@
+use crate::*;
+use alloc::vec::Vec;
+
+/// A marker [`Component`] for entities that should automatically be given an
+/// [`RngComponent`] by the [`RandomOnSpawnPlugin`]. Entities that already have an
+/// [`RngComponent`] are left untouched.
+#[derive(Debug, Default, Clone, Copy, Component)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct NeedsRng;
+
+/// An opt-in [`Plugin`] that automatically attaches an [`RngComponent`] to every entity
+/// that gets a [`NeedsRng`] marker, so spawn paths don't need to seed RNGs manually.
+/// Requires a [`GlobalRng`], such as from [`RngPlugin`].
+///
+/// The [`RngComponent`]s are seeded from the [`GlobalRng`] by a system in
+/// [`CoreStage::PostUpdate`], which picks up markers added during that frame, such as
+/// entities spawned in `Startup` or `Update`. Their [`RngComponent`] is then available from
+/// the following stage onwards. Entities are seeded in query iteration order, so with a
+/// seeded [`GlobalRng`] and the same spawn order, each entity gets the same seed every run.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Component)]
+/// struct Enemy;
+///
+/// fn spawn_enemies(mut commands: Commands) {
+///     commands.spawn((Enemy, NeedsRng));
+/// }
+///
+/// App::new()
+///     .add_plugin(RngPlugin::new().with_rng_seed(12345))
+///     .add_plugin(RandomOnSpawnPlugin)
+///     .add_startup_system(spawn_enemies)
+///     .run();
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct RandomOnSpawnPlugin;
+
+impl Plugin for RandomOnSpawnPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(CoreStage::PostUpdate, attach_rng);
+    }
+}
+
+/// Attaches an [`RngComponent`] seeded from the [`GlobalRng`] to every entity that was
+/// newly given a [`NeedsRng`] marker.
+fn attach_rng(
+    mut commands: Commands<'_, '_>,
+    mut global: ResMut<'_, GlobalRng>,
+    q_needs_rng: Query<'_, '_, Entity, (Added<NeedsRng>, Without<RngComponent>)>,
+) {
+    for entity in q_needs_rng.iter() {
+        commands
+            .entity(entity)
+            .insert(RngComponent::from(&mut global));
+    }
+}
+
+/// A system that reseeds the [`RngComponent`] of every entity that was newly given a
+/// [`Parent`] from the [`RngComponent`] of that parent, so hierarchies can be made
+/// deterministic without manually looking up parent entities. Add it to any stage after
+/// the hierarchy is built, such as [`CoreStage::PostUpdate`].
+///
+/// Each child forks its parent's [`RngComponent`], with siblings seeded in the order of
+/// the parent's [`Children`], and nested hierarchies seeded from the top down. So as long as
+/// the parent is seeded deterministically and the children are added in the same order, each
+/// child ends up with the same state every run. Children get reseeded whenever their
+/// [`Parent`] changes, such as when reparenting them, while entities whose parent has no
+/// [`RngComponent`] are left untouched.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn spawn_squad(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+///     commands
+///         .spawn(RngComponent::from(&mut global))
+///         .with_children(|squad| {
+///             squad.spawn(RngComponent::new());
+///             squad.spawn(RngComponent::new());
+///         });
+/// }
+///
+/// App::new()
+///     .add_plugin(RngPlugin::new().with_rng_seed(12345))
+///     .add_startup_system(spawn_squad)
+///     .add_system_to_stage(CoreStage::PostUpdate, seed_from_parent)
+///     .run();
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub fn seed_from_parent(
+    mut q_rng: Query<'_, '_, &mut RngComponent>,
+    q_new_children: Query<'_, '_, &Parent, (With<RngComponent>, Changed<Parent>)>,
+    q_children: Query<'_, '_, &Children>,
+) {
+    // Only start from parents that aren't being reseeded themselves, so each level of the
+    // hierarchy is seeded from the already reseeded level above it.
+    let mut roots: Vec<Entity> = q_new_children
+        .iter()
+        .map(Parent::get)
+        .filter(|&parent| !q_new_children.contains(parent))
+        .collect();
+
+    roots.sort_unstable();
+    roots.dedup();
+
+    for root in roots {
+        seed_children(root, &mut q_rng, &q_new_children, &q_children);
+    }
+}
+
+fn seed_children(
+    parent: Entity,
+    q_rng: &mut Query<'_, '_, &mut RngComponent>,
+    q_new_children: &Query<'_, '_, &Parent, (With<RngComponent>, Changed<Parent>)>,
+    q_children: &Query<'_, '_, &Children>,
+) {
+    if let Ok(children) = q_children.get(parent) {
+        for &child in children
+            .iter()
+            .filter(|&&child| q_new_children.contains(child))
+        {
+            if let Ok([mut parent_rng, mut child_rng]) = q_rng.get_many_mut([parent, child]) {
+                *child_rng = RngComponent::from(&mut parent_rng);
+            }
+
+            seed_children(child, q_rng, q_new_children, q_children);
+        }
+    }
+}
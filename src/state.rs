@@ -0,0 +1,366 @@
+use crate::*;
+
+/// The increment applied to the WyRand state for every generated `u64`.
+#[cfg(feature = "serialize")]
+const WYRAND_INCREMENT: u64 = 0xa076_1d64_78bd_642f;
+
+/// Advances the [`Rng`] state by `steps` draws. Uses a closed-form jump when the
+/// internal state can be read, otherwise discards `steps` generated values.
+#[inline]
+pub(crate) fn skip(rng: &Rng, steps: u64) {
+    #[cfg(feature = "serialize")]
+    rng.reseed(rng_state(rng).wrapping_add(steps.wrapping_mul(WYRAND_INCREMENT)));
+
+    #[cfg(not(feature = "serialize"))]
+    for _ in 0..steps {
+        rng.gen_u64();
+    }
+}
+
+/// The multiplicative inverse of [`WYRAND_INCREMENT`] modulo 2^64, computed by Newton's
+/// iteration. Each iteration doubles the number of correct low bits, starting from the 3
+/// bits any odd number gets right as its own inverse.
+#[cfg(feature = "serialize")]
+const WYRAND_INCREMENT_INVERSE: u64 = {
+    let mut inverse = WYRAND_INCREMENT;
+    let mut iteration = 0;
+
+    while iteration < 5 {
+        inverse = inverse.wrapping_mul(2_u64.wrapping_sub(WYRAND_INCREMENT.wrapping_mul(inverse)));
+        iteration += 1;
+    }
+
+    inverse
+};
+
+/// Returns the number of draws needed to advance an [`Rng`] from the state `before` to
+/// the state `after`, as the inverse of [`skip`].
+#[cfg(feature = "serialize")]
+#[inline]
+pub(crate) const fn draws_between(before: u64, after: u64) -> u64 {
+    after
+        .wrapping_sub(before)
+        .wrapping_mul(WYRAND_INCREMENT_INVERSE)
+}
+
+/// Reads the internal state of an [`Rng`]. `turborand` only exposes the state through
+/// its `Serialize` implementation, so a minimal [`Serializer`](serde::Serializer) is used
+/// to extract the single `u64` without needing any allocation.
+#[cfg(feature = "serialize")]
+#[inline]
+pub(crate) fn rng_state(rng: &Rng) -> u64 {
+    rng.serialize(extractor::StateExtractor)
+        .expect("the Rng state should always serialize as a single u64")
+}
+
+/// Errors that can occur when parsing an RNG state from a hex string, such as with
+/// [`GlobalRng::from_state_hex`] or [`RngComponent::from_state_hex`].
+#[cfg(feature = "serialize")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serialize", feature = "wyrand"))))]
+pub enum StateParseError {
+    /// The string did not contain exactly 16 hex digits. Contains the length received.
+    InvalidLength(usize),
+    /// The string contained a character that is not a hex digit.
+    InvalidDigit,
+}
+
+#[cfg(feature = "serialize")]
+impl core::fmt::Display for StateParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength(length) => write!(
+                f,
+                "RNG state hex must be 16 digits long, received {}",
+                length
+            ),
+            Self::InvalidDigit => f.write_str("RNG state hex contains an invalid digit"),
+        }
+    }
+}
+
+#[cfg(all(feature = "serialize", feature = "std"))]
+impl std::error::Error for StateParseError {}
+
+/// Creates an [`Rng`] with the given raw internal state, as read by [`rng_state`].
+#[cfg(feature = "serialize")]
+#[inline]
+pub(crate) fn from_state(state: u64) -> Rng {
+    let rng = Rng::with_seed(0);
+
+    rng.reseed(state);
+
+    rng
+}
+
+/// Encodes the internal state of an [`Rng`] as 16 lowercase hex digits.
+#[cfg(feature = "serialize")]
+#[inline]
+pub(crate) fn state_hex(rng: &Rng) -> alloc::string::String {
+    alloc::format!("{:016x}", rng_state(rng))
+}
+
+/// Decodes an [`Rng`] from the 16 hex digits produced by [`state_hex`], restoring the
+/// exact internal state. Both lowercase and uppercase digits are accepted.
+#[cfg(feature = "serialize")]
+pub(crate) fn from_state_hex(hex: &str) -> Result<Rng, StateParseError> {
+    if hex.len() != 16 {
+        return Err(StateParseError::InvalidLength(hex.len()));
+    }
+
+    // `from_str_radix` would also accept a leading sign, so check the digits up front
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(StateParseError::InvalidDigit);
+    }
+
+    let state = u64::from_str_radix(hex, 16).map_err(|_| StateParseError::InvalidDigit)?;
+
+    Ok(from_state(state))
+}
+
+#[cfg(feature = "serialize")]
+mod extractor {
+    use core::fmt::{self, Display};
+    use serde::ser::{self, Impossible, Serialize, Serializer, StdError};
+
+    #[derive(Debug)]
+    pub(super) struct StateError;
+
+    impl Display for StateError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("unexpected Rng state layout")
+        }
+    }
+
+    impl StdError for StateError {}
+
+    impl ser::Error for StateError {
+        fn custom<T: Display>(_msg: T) -> Self {
+            Self
+        }
+    }
+
+    macro_rules! reject {
+        ($($method:ident($($arg:ty),*)),* $(,)?) => {
+            $(
+                fn $method(self, $(_: $arg),*) -> Result<Self::Ok, Self::Error> {
+                    Err(StateError)
+                }
+            )*
+        };
+    }
+
+    pub(super) struct StateExtractor;
+
+    impl Serializer for StateExtractor {
+        type Ok = u64;
+        type Error = StateError;
+        type SerializeSeq = Impossible<u64, StateError>;
+        type SerializeTuple = Impossible<u64, StateError>;
+        type SerializeTupleStruct = Impossible<u64, StateError>;
+        type SerializeTupleVariant = Impossible<u64, StateError>;
+        type SerializeMap = Impossible<u64, StateError>;
+        type SerializeStruct = StructExtractor;
+        type SerializeStructVariant = Impossible<u64, StateError>;
+
+        fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+            Ok(v)
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            value.serialize(self)
+        }
+
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Self::Error> {
+            Ok(StructExtractor(None))
+        }
+
+        reject!(
+            serialize_bool(bool),
+            serialize_i8(i8),
+            serialize_i16(i16),
+            serialize_i32(i32),
+            serialize_i64(i64),
+            serialize_u8(u8),
+            serialize_u16(u16),
+            serialize_u32(u32),
+            serialize_f32(f32),
+            serialize_f64(f64),
+            serialize_char(char),
+            serialize_str(&str),
+            serialize_bytes(&[u8]),
+            serialize_none(),
+            serialize_unit(),
+            serialize_unit_struct(&'static str),
+            serialize_unit_variant(&'static str, u32, &'static str),
+        );
+
+        fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+            Err(StateError)
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(StateError)
+        }
+
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Err(StateError)
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Err(StateError)
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Err(StateError)
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(StateError)
+        }
+
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            Err(StateError)
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(StateError)
+        }
+    }
+
+    pub(super) struct StructExtractor(Option<u64>);
+
+    impl ser::SerializeStruct for StructExtractor {
+        type Ok = u64;
+        type Error = StateError;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Self::Error> {
+            if key == "state" {
+                self.0 = Some(value.serialize(StateExtractor)?);
+            }
+
+            Ok(())
+        }
+
+        fn end(self) -> Result<Self::Ok, Self::Error> {
+            self.0.ok_or(StateError)
+        }
+    }
+}
+
+/// Where the state of an RNG wrapper started from, so that [`GlobalRng::draws`] and
+/// [`RngComponent::draws`] can count draws by how far the state has advanced since.
+/// Zero sized unless the `rng_stats` feature is enabled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DrawStats {
+    #[cfg(feature = "rng_stats")]
+    origin: u64,
+}
+
+impl DrawStats {
+    #[inline]
+    #[cfg_attr(not(feature = "rng_stats"), allow(unused_variables))]
+    pub(crate) fn new(rng: &Rng) -> Self {
+        Self {
+            #[cfg(feature = "rng_stats")]
+            origin: rng_state(rng),
+        }
+    }
+
+    /// Returns the number of draws made from `rng` since the stats were created.
+    #[cfg(feature = "rng_stats")]
+    #[inline]
+    pub(crate) fn draws(&self, rng: &Rng) -> u64 {
+        draws_between(self.origin, rng_state(rng))
+    }
+
+    /// Reseeds `rng` into the same state as [`Rng::with_seed`] would create, keeping the
+    /// number of draws counted so far. [`SeededCore::reseed`] instead stores the seed as
+    /// the raw state, which would not replay the sequence of the same seed.
+    #[inline]
+    pub(crate) fn reseed(&mut self, rng: &mut Rng, seed: u64) {
+        #[cfg(feature = "rng_stats")]
+        let draws = self.draws(rng);
+
+        *rng = Rng::with_seed(seed);
+
+        #[cfg(feature = "rng_stats")]
+        {
+            self.origin = rng_state(rng).wrapping_sub(draws.wrapping_mul(WYRAND_INCREMENT));
+        }
+    }
+}
+
+/// Implements [`Serialize`] and [`Deserialize`] for an RNG wrapper in the same format as a
+/// derived newtype struct around its [`Rng`], leaving out its [`DrawStats`] so that saves
+/// are the same no matter whether the `rng_stats` feature is enabled. An accessor method can
+/// be given for wrappers whose [`Rng`] can't be read directly from a shared reference.
+#[cfg(feature = "serialize")]
+macro_rules! impl_newtype_serde {
+    ($wrapper:ident, $name:literal) => {
+        impl Serialize for $wrapper {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_newtype_struct($name, &self.0)
+            }
+        }
+
+        $crate::state::impl_newtype_serde!(@deserialize $wrapper, $name);
+    };
+    ($wrapper:ident, $name:literal, $source:ident) => {
+        impl Serialize for $wrapper {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_newtype_struct($name, &*self.$source())
+            }
+        }
+
+        $crate::state::impl_newtype_serde!(@deserialize $wrapper, $name);
+    };
+    (@deserialize $wrapper:ident, $name:literal) => {
+        impl<'de> Deserialize<'de> for $wrapper {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                #[derive(Deserialize)]
+                #[serde(rename = $name)]
+                struct Newtype(Rng);
+
+                Newtype::deserialize(deserializer).map(|Newtype(rng)| Self::from_rng(rng))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serialize")]
+pub(crate) use impl_newtype_serde;
@@ -0,0 +1,112 @@
+use crate::*;
+use alloc::{collections::BTreeMap, string::String};
+
+/// A registry of named, independent [`Rng`] streams derived from a single master seed, for
+/// keeping the randomness of separate subsystems such as AI, loot and weather from affecting
+/// each other. With a single shared RNG, an extra draw in one subsystem shifts the values
+/// seen by every other one, whereas each named stream only advances when drawn from.
+///
+/// Streams are created lazily the first time they are requested with [`RngStreams::stream`],
+/// each seeded from a hash of its name mixed with the master seed. The same name always
+/// yields the same stream for the same master seed, regardless of the order in which streams
+/// are created. Names are hashed with 64-bit FNV-1a over their UTF-8 bytes, as with
+/// [`GlobalRng::with_string_seed`], so the seeds are the same on all platforms, Rust versions
+/// and versions of this crate.
+///
+/// [`RngStreams`] can be inserted as a [`Resource`], or initialised with `init_resource`, in
+/// which case it is seeded from the [`GlobalRng`] resource if present, or with a randomised
+/// master seed otherwise.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// fn change_weather(mut streams: ResMut<RngStreams>) {
+///     let raining = streams.stream("weather").bool();
+///
+///     println!("Raining: {}", raining);
+/// }
+/// # bevy::ecs::system::assert_is_system(change_weather);
+///
+/// App::new()
+///     .insert_resource(RngStreams::with_seed(12345))
+///     .add_system(change_weather)
+///     .run();
+/// ```
+#[derive(Debug, Resource)]
+#[cfg_attr(docsrs, doc(cfg(feature = "wyrand")))]
+pub struct RngStreams {
+    master_seed: u64,
+    streams: BTreeMap<String, Rng>,
+}
+
+unsafe impl Sync for RngStreams {}
+
+impl RngStreams {
+    /// Create a new [`RngStreams`] registry with a randomised master seed.
+    // No `Default`, as it would conflict with the `FromWorld` impl seeding from `GlobalRng`.
+    #[allow(clippy::new_without_default)]
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_seed(Rng::new().gen_u64())
+    }
+
+    /// Create a new [`RngStreams`] registry with a given master seed.
+    #[inline]
+    #[must_use]
+    pub const fn with_seed(master_seed: u64) -> Self {
+        Self {
+            master_seed,
+            streams: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the master seed that the streams are derived from.
+    #[inline]
+    #[must_use]
+    pub const fn master_seed(&self) -> u64 {
+        self.master_seed
+    }
+
+    /// Returns the stream with the given name, creating it if it hasn't been requested yet.
+    pub fn stream(&mut self, name: &str) -> &mut Rng {
+        if !self.streams.contains_key(name) {
+            let name_hash = crate::hash::fnv1a(name.as_bytes());
+            let seed = crate::hash::mix_seed(self.master_seed, name_hash);
+
+            self.streams.insert(name.into(), Rng::with_seed(seed));
+        }
+
+        self.streams
+            .get_mut(name)
+            .expect("the stream should have been created if missing")
+    }
+
+    /// Returns the number of streams created so far.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Returns `true` if no streams have been created yet.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+impl FromWorld for RngStreams {
+    /// Creates an [`RngStreams`] registry seeded from the [`GlobalRng`] resource if present,
+    /// otherwise with a randomised master seed.
+    fn from_world(world: &mut World) -> Self {
+        world
+            .get_resource_mut::<GlobalRng>()
+            .map_or_else(Self::new, |mut global| {
+                Self::with_seed(global.fork().gen_u64())
+            })
+    }
+}
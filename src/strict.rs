@@ -0,0 +1,47 @@
+//! Tracking of the seeding contexts allowed to draw from a [`GlobalRng`] in strict mode.
+//!
+//! [`GlobalRng`]: crate::GlobalRng
+
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static SEEDING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `seed` as a seeding context, within which draws from a strict [`GlobalRng`] are
+/// allowed, such as for creating new [`RngComponent`]s from it.
+///
+/// [`GlobalRng`]: crate::GlobalRng
+/// [`RngComponent`]: crate::RngComponent
+#[inline]
+pub(crate) fn seeding<R>(seed: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "std")]
+    let _guard = SeedingGuard(SEEDING.with(|seeding| seeding.replace(true)));
+
+    seed()
+}
+
+/// Restores the previous seeding state once dropped, so that a seeding context that panics
+/// doesn't leave the thread allowed to draw if the panic gets caught.
+#[cfg(feature = "std")]
+struct SeedingGuard(bool);
+
+#[cfg(feature = "std")]
+impl Drop for SeedingGuard {
+    #[inline]
+    fn drop(&mut self) {
+        SEEDING.with(|seeding| seeding.set(self.0));
+    }
+}
+
+/// Panics if the current thread is not within a seeding context.
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn assert_seeding() {
+    assert!(
+        SEEDING.with(Cell::get),
+        "GlobalRng was drawn from directly while in strict mode, seed an RngComponent from it instead"
+    );
+}
@@ -0,0 +1,173 @@
+use crate::*;
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+
+/// Errors that can occur when building a [`WeightedTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeightedTableError {
+    /// The number of items and weights provided did not match.
+    LengthMismatch {
+        /// The number of items provided.
+        items: usize,
+        /// The number of weights provided.
+        weights: usize,
+    },
+    /// None of the weights were positive and finite, so no item could ever be sampled.
+    /// Also returned when no items are provided.
+    NoValidWeights,
+}
+
+impl Display for WeightedTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { items, weights } => write!(
+                f,
+                "weighted table received {} items but {} weights",
+                items, weights
+            ),
+            Self::NoValidWeights => f.write_str("weighted table has no valid weights"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WeightedTableError {}
+
+/// A single column of the alias table. Sampling picks a column uniformly, then returns
+/// `item` if a uniform draw falls under `threshold`, and `alias` otherwise.
+#[derive(Debug, Clone, Copy)]
+struct AliasEntry {
+    threshold: f64,
+    item: usize,
+    alias: usize,
+}
+
+/// A precomputed table for repeatedly sampling items with fixed weights in constant time,
+/// using [Vose's alias method](https://www.keithschwarz.com/darts-dice-coins/). Building
+/// the table is O(n), after which each [`WeightedTable::sample`] costs a single index draw
+/// and a single `f64` draw, regardless of the number of items. This makes it well suited for
+/// loot tables and the like that get sampled many times, as opposed to
+/// [`DelegatedRng::weighted_sample`], which is O(n) for every sample.
+///
+/// Like [`DelegatedRng::sample_weighted_iter`], weights only need to be relative to each
+/// other, and items with a weight that is zero, negative or not finite are never sampled.
+/// The column index is drawn as a `u64`, so sampling with the same RNG state selects the same
+/// items on 32-bit and 64-bit platforms.
+///
+/// # Example
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Resource)]
+/// struct LootTable(WeightedTable<&'static str>);
+///
+/// fn drop_loot(table: Res<LootTable>, mut global: ResMut<GlobalRng>) {
+///     let drop = table.0.sample(&mut *global);
+///
+///     println!("Dropped: {}", drop);
+/// }
+/// # bevy::ecs::system::assert_is_system(drop_loot);
+///
+/// let table = WeightedTable::new(vec!["sword", "potion", "gold"], &[1.0, 10.0, 20.0]).unwrap();
+/// let mut rng = RngComponent::with_seed(12345);
+///
+/// assert!(["sword", "potion", "gold"].contains(table.sample(&mut rng)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WeightedTable<T> {
+    items: Vec<T>,
+    entries: Vec<AliasEntry>,
+}
+
+impl<T> WeightedTable<T> {
+    /// Builds a new [`WeightedTable`] from a list of items and their matching weights.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WeightedTableError::LengthMismatch`] if the number of items and weights
+    /// differ, or [`WeightedTableError::NoValidWeights`] if no weight is positive and finite.
+    pub fn new(items: Vec<T>, weights: &[f64]) -> Result<Self, WeightedTableError> {
+        if items.len() != weights.len() {
+            return Err(WeightedTableError::LengthMismatch {
+                items: items.len(),
+                weights: weights.len(),
+            });
+        }
+
+        let valid: Vec<(usize, f64)> = weights
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, weight)| weight > 0.0 && weight.is_finite())
+            .collect();
+
+        if valid.is_empty() {
+            return Err(WeightedTableError::NoValidWeights);
+        }
+
+        // Normalising by the largest weight first keeps the total from overflowing
+        let max = valid
+            .iter()
+            .fold(0.0_f64, |max, &(_, weight)| max.max(weight));
+        let total: f64 = valid.iter().map(|&(_, weight)| weight / max).sum();
+        let scale = valid.len() as f64 / total;
+
+        let mut probabilities: Vec<f64> = valid
+            .iter()
+            .map(|&(_, weight)| weight / max * scale)
+            .collect();
+        let mut entries: Vec<AliasEntry> = valid
+            .iter()
+            .map(|&(item, _)| AliasEntry {
+                threshold: 1.0,
+                item,
+                alias: item,
+            })
+            .collect();
+
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..valid.len()).partition(|&column| probabilities[column] < 1.0);
+
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+
+            entries[less].threshold = probabilities[less];
+            entries[less].alias = entries[more].item;
+
+            probabilities[more] = (probabilities[more] + probabilities[less]) - 1.0;
+
+            if probabilities[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+
+        // Whatever remains in either list is only off from 1.0 due to rounding error, so
+        // those columns always select their own item, as already initialised.
+
+        Ok(Self { items, entries })
+    }
+
+    /// Samples an item from the table in constant time.
+    #[inline]
+    pub fn sample<R: DelegatedRng>(&self, rng: &mut R) -> &T {
+        let rng = rng.get_mut();
+        let entry = &self.entries[rng.u64(..self.entries.len() as u64) as usize];
+
+        let index = if rng.f64() < entry.threshold {
+            entry.item
+        } else {
+            entry.alias
+        };
+
+        &self.items[index]
+    }
+
+    /// Returns the items in the table, in the order they were provided.
+    #[inline]
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+}
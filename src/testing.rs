@@ -0,0 +1,62 @@
+//! Helpers for writing determinism tests against code using `bevy_turborand`.
+//!
+//! # Example
+//! ```
+//! use bevy_turborand::prelude::*;
+//! use bevy_turborand::testing::assert_deterministic;
+//!
+//! fn generate_loot(rng: &mut Rng) -> Vec<u32> {
+//!     (0..rng.usize(1..5)).map(|_| rng.u32(..100)).collect()
+//! }
+//!
+//! assert_deterministic(12345, 5, generate_loot);
+//! ```
+use crate::*;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+/// Runs `f` `runs` times, each time with a fresh [`Rng`] seeded with `seed`, and asserts
+/// that every run produces the same output as the first.
+///
+/// # Panics
+///
+/// Panics if `runs` is less than two, or if any run produces different output, in which
+/// case the panic message points out the run and index at which the outputs diverged.
+#[track_caller]
+pub fn assert_deterministic<T, F>(seed: u64, runs: usize, mut f: F)
+where
+    T: PartialEq + Debug,
+    F: FnMut(&mut Rng) -> Vec<T>,
+{
+    assert!(
+        runs >= 2,
+        "assert_deterministic needs at least 2 runs to compare, got {}",
+        runs
+    );
+
+    let expected = f(&mut Rng::with_seed(seed));
+
+    for run in 1..runs {
+        let received = f(&mut Rng::with_seed(seed));
+
+        if let Some(index) = expected
+            .iter()
+            .zip(received.iter())
+            .position(|(expected, received)| expected != received)
+        {
+            panic!(
+                "run {} with seed {} diverged from the first run at index {}: expected {:?}, got {:?}",
+                run, seed, index, expected[index], received[index]
+            );
+        }
+
+        assert!(
+            expected.len() == received.len(),
+            "run {} with seed {} produced {} values, but the first run produced {}",
+            run,
+            seed,
+            received.len(),
+            expected.len()
+        );
+    }
+}
@@ -1,9 +1,20 @@
 use crate::*;
-use std::{fmt::Debug, ops::RangeBounds};
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::Debug,
+    ops::{RangeBounds, RangeInclusive},
+};
+use smallvec::SmallVec;
 
 #[cfg(feature = "rand")]
 use crate::RandBorrowed;
 
+#[cfg(feature = "bevy_color")]
+use bevy::render::color::Color;
+
+#[cfg(feature = "bevy_math")]
+use core::f32::consts::TAU;
+
 /// A trait for applying to [`Component`]s and Resources that wrap a [`TurboCore`] RNG source.
 ///
 /// It provides a threadsafe layer to access the underlying [`TurboCore`] source, which it achieves
@@ -18,6 +29,15 @@ use crate::RandBorrowed;
 /// not always the most ergonomic way to use the RNG, so getting to the inner [`TurboCore`] source via
 /// [`DelegatedRng::get_mut`] is the other manner to use the RNG within a system, giving access to
 /// `&self` methods of [`TurboRand`].
+///
+/// # Uniformity of integer ranges
+///
+/// The integer range methods, such as [`DelegatedRng::u64`] and [`DelegatedRng::gen_range`],
+/// are free of modulo bias for any range width. [`TurboRand`] maps each draw into the range
+/// with Lemire's widening multiply, rejecting and redrawing the few values that would
+/// otherwise make some outcomes more likely than others, so every value within the range is
+/// equally likely. Methods built on top of them, such as [`DelegatedRng::index`] and
+/// [`DelegatedRng::chance_in`], are exactly uniform as a result.
 pub trait DelegatedRng
 where
     Self::Source: Default
@@ -75,7 +95,26 @@ where
     }
 
     /// Return a compatibility shim for working with crates from the `rand`
-    /// ecosystem.
+    /// ecosystem. The shim implements `RngCore`, so it can be passed wherever `rand`
+    /// expects an RNG, such as the `SliceRandom` and `IteratorRandom` extension traits.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    /// use rand::seq::{IteratorRandom, SliceRandom};
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let enemies = ["goblin", "orc", "troll"];
+    ///
+    /// let target = enemies.choose(&mut rng.as_rand()).unwrap();
+    /// let squad: Vec<_> = enemies.choose_multiple(&mut rng.as_rand(), 2).collect();
+    /// let roll = (1..=20).choose(&mut rng.as_rand()).unwrap();
+    ///
+    /// assert!(enemies.contains(target));
+    /// assert_eq!(squad.len(), 2);
+    /// assert!((1..=20).contains(&roll));
+    /// ```
     #[cfg(feature = "rand")]
     #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
     #[inline]
@@ -193,6 +232,14 @@ where
         char,
         "Delegated [`TurboRand::uppercase`] method from [`TurboRand`]."
     );
+
+    /// Generates a random printable ASCII character, from `' '` to `'~'` inclusive,
+    /// for procedural names and codes. Excludes control characters.
+    #[inline]
+    fn ascii(&mut self) -> char {
+        self.get_mut().char(' '..='~')
+    }
+
     delegate_rng_trait!(
         bool,
         bool,
@@ -225,12 +272,308 @@ where
         self.get_mut().fill_bytes(buffer);
     }
 
+    /// Fills a slice of `bool`s where each entry is `true` with the given `probability`,
+    /// such as for generating cave masks and tilemaps in one call. Each generated `u64` is
+    /// split into two 32-bit halves that are compared against the probability, so filling
+    /// takes half as many draws as calling [`TurboRand::chance`] for every entry, at the cost
+    /// of the probability being resolved to 32 bits of precision rather than 64.
+    ///
+    /// Like [`TurboRand::chance`], a `probability` of exactly `0.0` or `1.0` fills the slice
+    /// without drawing from the RNG at all. Any other probability draws the same number of
+    /// values for the same slice length, with the probability rounded to the nearest multiple
+    /// of `2^-32`, so probabilities below `2^-33` never produce `true` but still draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probability` is not between `0.0` and `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    /// let mut walls = [false; 64 * 64];
+    ///
+    /// rng.fill_bools(&mut walls, 0.45);
+    ///
+    /// assert!(walls.iter().any(|&wall| wall));
+    /// ```
+    #[inline]
+    fn fill_bools(&mut self, dest: &mut [bool], probability: f64) {
+        const SCALE: f64 = (1_u64 << 32) as f64;
+
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "probability is not between 0.0 and 1.0, received {}",
+            probability
+        );
+
+        if probability == 0.0 || probability == 1.0 {
+            dest.fill(probability == 1.0);
+
+            return;
+        }
+
+        // Rounds to the nearest threshold, as `f64::round` isn't available without `std`
+        let threshold = (probability * SCALE + 0.5) as u64;
+        let rng = self.get_mut();
+
+        for pair in dest.chunks_mut(2) {
+            let bits = rng.gen_u64();
+
+            for (index, entry) in pair.iter_mut().enumerate() {
+                *entry = ((bits >> (32 * index)) & u64::from(u32::MAX)) < threshold;
+            }
+        }
+    }
+
     /// Delegated [`TurboRand::shuffle`] method from [`TurboRand`].
     #[inline]
     fn shuffle<T>(&mut self, slice: &mut [T]) {
         self.get_mut().shuffle(slice);
     }
 
+    /// Shuffles a slice in place, producing the same permutation on 32-bit and 64-bit
+    /// platforms for the same RNG state. Unlike [`DelegatedRng::shuffle`], which generates
+    /// `usize` indices and so diverges between pointer widths, this generates `u64`
+    /// indices with an unbiased bounded range, which always fit the slice. This is the
+    /// recommended way of shuffling for lockstep networking or any other case where
+    /// results must match across platforms.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    /// let mut deck: Vec<u32> = (1..=52).collect();
+    ///
+    /// rng.shuffle_portable(&mut deck);
+    ///
+    /// assert_eq!(deck.len(), 52);
+    /// ```
+    #[inline]
+    fn shuffle_portable<T>(&mut self, slice: &mut [T]) {
+        portable_shuffle(self.get_mut(), slice);
+    }
+
+    /// Shuffles a slice in place like [`DelegatedRng::shuffle_portable`], returning the
+    /// permutation that was applied, so the same shuffle can be replayed elsewhere with
+    /// [`apply_permutation`] without needing the RNG, such as sending a shuffled deck's order
+    /// over the network. Each entry is the original index of the element that ended up at that
+    /// position, so `shuffled[i] == original[permutation[i]]`.
+    ///
+    /// Consumes the same draws as [`DelegatedRng::shuffle_portable`], so both produce the same
+    /// order for the same RNG state.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    /// let mut deck: Vec<u32> = (1..=52).collect();
+    /// let mut remote_deck = deck.clone();
+    ///
+    /// let permutation = rng.shuffle_with_indices(&mut deck);
+    ///
+    /// apply_permutation(&mut remote_deck, &permutation);
+    ///
+    /// assert_eq!(remote_deck, deck);
+    /// ```
+    #[inline]
+    fn shuffle_with_indices<T>(&mut self, slice: &mut [T]) -> Vec<usize> {
+        let rng = self.get_mut();
+        let mut permutation: Vec<usize> = (0..slice.len()).collect();
+
+        for index in (1..slice.len()).rev() {
+            let swap = rng.u64(..=index as u64) as usize;
+
+            slice.swap(index, swap);
+            permutation.swap(index, swap);
+        }
+
+        permutation
+    }
+
+    /// Returns a shuffled copy of a slice, leaving the original untouched. Saves cloning the
+    /// slice into a `Vec` before shuffling it, such as for dealing from a deck that has to
+    /// stay in order. Produces the same order as [`DelegatedRng::shuffle_portable`] for the
+    /// same RNG state, so the result is the same on 32-bit and 64-bit platforms.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    /// let deck: Vec<u32> = (1..=52).collect();
+    ///
+    /// let shuffled = rng.permutation(&deck);
+    ///
+    /// assert_eq!(shuffled.len(), deck.len());
+    /// assert_eq!(deck[0], 1);
+    /// ```
+    #[inline]
+    fn permutation<T: Clone>(&mut self, list: &[T]) -> Vec<T> {
+        let mut permuted = list.to_vec();
+
+        self.shuffle_portable(&mut permuted);
+
+        permuted
+    }
+
+    /// Partially shuffles a slice in place, so that only the first `k` elements are
+    /// randomised, forming a uniformly random sample of the slice in a random order. Runs
+    /// the first `k` steps of a Fisher-Yates shuffle, so it only performs `k` swaps, which is
+    /// cheaper than a full [`DelegatedRng::shuffle_portable`] when only a few elements are
+    /// needed, such as drawing a hand of cards from a deck. The order of the remaining
+    /// elements is unspecified. `k` is clamped to the length of the slice. Like
+    /// [`DelegatedRng::shuffle_portable`], indices are drawn as `u64`s, so the result is the
+    /// same on 32-bit and 64-bit platforms.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    /// let mut deck: Vec<u32> = (1..=52).collect();
+    ///
+    /// rng.shuffle_partial(&mut deck, 5);
+    ///
+    /// let hand = &deck[..5];
+    ///
+    /// assert_eq!(hand.len(), 5);
+    /// ```
+    #[inline]
+    fn shuffle_partial<T>(&mut self, slice: &mut [T], k: usize) {
+        let rng = self.get_mut();
+        let len = slice.len();
+
+        for position in 0..k.min(len) {
+            let swap = rng.u64(position as u64..len as u64) as usize;
+
+            slice.swap(position, swap);
+        }
+    }
+
+    /// Samples a random item from a slice of values, selecting the same item on 32-bit
+    /// and 64-bit platforms for the same RNG state. Portable counterpart to
+    /// [`DelegatedRng::sample`], see [`DelegatedRng::shuffle_portable`] for more details.
+    #[inline]
+    fn sample_portable<'a, T>(&mut self, list: &'a [T]) -> Option<&'a T> {
+        portable_sample(self.get_mut(), list)
+    }
+
+    /// Samples multiple unique items from a slice of values, selecting the same items in
+    /// the same order on 32-bit and 64-bit platforms for the same RNG state. Portable
+    /// counterpart to [`DelegatedRng::sample_multiple`], see [`DelegatedRng::shuffle_portable`]
+    /// for more details.
+    #[inline]
+    fn sample_multiple_portable<'a, T>(&mut self, list: &'a [T], amount: usize) -> Vec<&'a T> {
+        let draining = list.len().min(amount);
+
+        let mut shuffled: Vec<&'a T> = list.iter().collect();
+
+        portable_shuffle(self.get_mut(), &mut shuffled);
+
+        shuffled.truncate(draining);
+        shuffled
+    }
+
+    /// Samples multiple unique items from a slice of values in a random order, without any
+    /// heap allocation for slices of up to 64 items, such as for picking a few distinct
+    /// targets in a hot system. The chosen indices are tracked with a single `u64` bitmask,
+    /// and the items are returned in a [`SmallVec`] that stores up to 64 items inline. Larger
+    /// slices fall back to a partial shuffle of the items, which allocates. Returns every item
+    /// in a random order if `amount` is greater than the length of the slice.
+    ///
+    /// Indices are drawn as `u64`s, so the selection is the same on 32-bit and 64-bit
+    /// platforms for the same RNG state.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let enemies = ["goblin", "orc", "troll", "dragon"];
+    ///
+    /// let targets = rng.sample_distinct_small(&enemies, 2);
+    ///
+    /// assert_eq!(targets.len(), 2);
+    /// assert_ne!(targets[0], targets[1]);
+    /// ```
+    #[inline]
+    fn sample_distinct_small<'a, T>(
+        &mut self,
+        list: &'a [T],
+        amount: usize,
+    ) -> SmallVec<[&'a T; 64]> {
+        let rng = self.get_mut();
+        let amount = amount.min(list.len());
+
+        if list.len() > 64 {
+            let mut shuffled: Vec<&'a T> = list.iter().collect();
+
+            for position in 0..amount {
+                let swap = rng.u64(position as u64..list.len() as u64) as usize;
+
+                shuffled.swap(position, swap);
+            }
+
+            return shuffled.into_iter().take(amount).collect();
+        }
+
+        let mut chosen = 0_u64;
+        let mut sampled = SmallVec::new();
+
+        for remaining in ((list.len() - amount + 1)..=list.len()).rev() {
+            // Find the nth index that hasn't been chosen yet
+            let mut skip = rng.u64(..remaining as u64);
+            let mut index = 0;
+
+            loop {
+                if chosen & (1 << index) == 0 {
+                    if skip == 0 {
+                        break;
+                    }
+
+                    skip -= 1;
+                }
+
+                index += 1;
+            }
+
+            chosen |= 1 << index;
+            sampled.push(&list[index]);
+        }
+
+        sampled
+    }
+
+    /// Samples a random item from a slice of values based on the weights given by
+    /// `weight_sampler`, selecting the same item on 32-bit and 64-bit platforms for the same
+    /// RNG state. Portable counterpart to [`DelegatedRng::weighted_sample`], see
+    /// [`DelegatedRng::shuffle_portable`] for more details.
+    #[inline]
+    fn weighted_sample_portable<'a, T, F>(
+        &mut self,
+        list: &'a [T],
+        weight_sampler: F,
+    ) -> Option<&'a T>
+    where
+        F: Fn(&'a T) -> f64,
+    {
+        let rng: &Self::Source = self.get_mut();
+
+        match list.len() {
+            0 => None,
+            1 => list.first(),
+            _ => core::iter::repeat_with(|| portable_sample(rng, list))
+                .flatten()
+                .find(|&item| rng.chance(weight_sampler(item))),
+        }
+    }
+
     /// Delegated [`TurboRand::sample`] method from [`TurboRand`].
     #[inline]
     fn sample<'a, T>(&mut self, list: &'a [T]) -> Option<&'a T> {
@@ -249,6 +592,35 @@ where
         self.get_mut().sample_multiple(list, amount)
     }
 
+    /// Samples multiple unique items from a slice of values into a caller-owned buffer,
+    /// clearing it first and then reusing its capacity, so that sampling in hot loops
+    /// doesn't allocate once the buffer is large enough. Selects the same items in the same
+    /// order as [`DelegatedRng::sample_multiple`] for the same RNG state.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    /// let enemies = ["goblin", "orc", "troll", "bat", "slime"];
+    /// let mut targets = Vec::with_capacity(enemies.len());
+    ///
+    /// for _ in 0..3 {
+    ///     rng.sample_multiple_into(&enemies, &mut targets, 2);
+    ///
+    ///     assert_eq!(targets.len(), 2);
+    /// }
+    /// ```
+    #[inline]
+    fn sample_multiple_into<'a, T>(&mut self, list: &'a [T], out: &mut Vec<&'a T>, amount: usize) {
+        out.clear();
+        out.extend(list.iter());
+
+        self.get_mut().shuffle(out);
+
+        out.truncate(amount);
+    }
+
     /// Delegated [`TurboRand::sample_multiple_mut`] method from [`TurboRand`].
     #[inline]
     fn sample_multiple_mut<'a, T>(&mut self, list: &'a mut [T], amount: usize) -> Vec<&'a mut T> {
@@ -264,12 +636,1928 @@ where
         self.get_mut().weighted_sample(list, weight_sampler)
     }
 
+    /// Samples a random item from a slice of values based on the integer weights given by
+    /// `weight_sampler`, with each item being chosen with a probability proportional to its
+    /// weight, such as for loot tables with integer drop weights. Unlike
+    /// [`DelegatedRng::weighted_sample`], weights are not limited to between `0.0` and `1.0`,
+    /// and both the cumulative sum and the draw are done entirely with integer math, so the
+    /// probabilities are exact and the selection is bit-identical across platforms. Items with
+    /// a weight of zero are never selected. Returns `None` if the slice is empty, or if every
+    /// weight is zero.
+    ///
+    /// The weights are summed without overflowing, and `weight_sampler` gets called twice for
+    /// every item, once for summing the weights and once for finding the selected item, so it
+    /// should always return the same weight for the same item.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let loot = [("sword", 1), ("potion", 10), ("gold", 20)];
+    ///
+    /// let (drop, _) = rng.weighted_sample_int(&loot, |&(_, weight)| weight).unwrap();
+    ///
+    /// println!("Dropped: {}", drop);
+    /// ```
+    #[inline]
+    fn weighted_sample_int<'a, T, F>(&mut self, list: &'a [T], weight_sampler: F) -> Option<&'a T>
+    where
+        F: Fn(&'a T) -> u64,
+    {
+        let total: u128 = list
+            .iter()
+            .map(|item| u128::from(weight_sampler(item)))
+            .sum();
+
+        if total == 0 {
+            return None;
+        }
+
+        let rng = self.get_mut();
+
+        // Only fall back to the costlier 128-bit draw when the total doesn't fit a `u64`
+        let mut target = match u64::try_from(total) {
+            Ok(total) => u128::from(rng.u64(..total)),
+            Err(_) => rng.u128(..total),
+        };
+
+        list.iter().find(|&item| {
+            let weight = u128::from(weight_sampler(item));
+
+            if target < weight {
+                true
+            } else {
+                target -= weight;
+                false
+            }
+        })
+    }
+
     /// Delegated [`TurboRand::weighted_sample_mut`] method from [`TurboRand`].
+    #[inline]
     fn weighted_sample_mut<'a, T, F>(
         &'a mut self,
         list: &'a mut [T],
         weight_sampler: F,
     ) -> Option<&'a mut T>
     where
-        F: Fn(&T) -> f64;
+        F: Fn(&T) -> f64,
+    {
+        // Reborrow as a shared reference, as `&mut Self::Source` is also a `TurboRand`
+        // source, which would otherwise tie the output to a temporary reference.
+        let rng: &'a Self::Source = self.get_mut();
+
+        rng.weighted_sample_mut(list, weight_sampler)
+    }
+
+    /// Returns a normally distributed `f64` value with the given mean and standard
+    /// deviation, using the [Box-Muller transform](https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform)
+    /// on top of two uniform [`TurboRand::f64`] draws.
+    ///
+    /// The output is deterministic for a seeded source, though as it relies on `ln`, `sqrt`
+    /// and `cos`, values may differ in the last bits between platforms with different
+    /// floating point implementations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std_dev` is negative or not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let jitter = rng.f64_normal(0.0, 0.5);
+    ///
+    /// assert!(jitter.is_finite());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn f64_normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        assert!(
+            std_dev >= 0.0 && std_dev.is_finite(),
+            "std_dev must be a finite, non-negative value, received {}",
+            std_dev
+        );
+
+        let rng = self.get_mut();
+
+        // Shift the first draw into (0, 1] so to never take the logarithm of zero.
+        let radius = (-2.0 * (1.0 - rng.f64()).ln()).sqrt();
+        let angle = core::f64::consts::TAU * rng.f64();
+
+        mean + std_dev * radius * angle.cos()
+    }
+
+    /// Returns a normally distributed `f32` value with the given mean and standard
+    /// deviation. See [`DelegatedRng::f64_normal`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std_dev` is negative or not finite.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn f32_normal(&mut self, mean: f32, std_dev: f32) -> f32 {
+        self.f64_normal(f64::from(mean), f64::from(std_dev)) as f32
+    }
+
+    /// Returns a pair of independent, normally distributed `f64` values with the given mean
+    /// and standard deviation. The [Box-Muller transform](https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform)
+    /// yields two values for every two uniform draws, so this costs the same as a single
+    /// [`DelegatedRng::f64_normal`] call, which only keeps the first of the pair. Useful for
+    /// generating values that come in twos, such as 2D jitter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std_dev` is negative or not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let (x, y) = rng.f64_normal_pair(0.0, 0.5);
+    ///
+    /// assert!(x.is_finite() && y.is_finite());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn f64_normal_pair(&mut self, mean: f64, std_dev: f64) -> (f64, f64) {
+        assert!(
+            std_dev >= 0.0 && std_dev.is_finite(),
+            "std_dev must be a finite, non-negative value, received {}",
+            std_dev
+        );
+
+        let rng = self.get_mut();
+
+        let radius = (-2.0 * (1.0 - rng.f64()).ln()).sqrt();
+        let (sin, cos) = (core::f64::consts::TAU * rng.f64()).sin_cos();
+
+        (mean + std_dev * radius * cos, mean + std_dev * radius * sin)
+    }
+
+    /// Samples a random item from an iterator using a weight for each item, without
+    /// needing to collect the iterator first. Uses the A-Res weighted reservoir sampling
+    /// algorithm from [Efraimidis & Spirakis](https://doi.org/10.1016/j.ipl.2005.11.003),
+    /// consuming the whole iterator in O(n) time while using O(1) memory.
+    ///
+    /// Unlike [`DelegatedRng::weighted_sample`], weights are not limited to between `0.0`
+    /// and `1.0`, and only need to be relative to each other. Items with a weight that is
+    /// zero, negative or not finite are never selected. Returns `None` if the iterator is
+    /// empty or contains no items with a valid weight.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let loot = (1..=5).map(|tier| tier * 10);
+    ///
+    /// let drop = rng.sample_weighted_iter(loot, |&value| 1.0 / f64::from(value));
+    ///
+    /// assert!(drop.is_some());
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn sample_weighted_iter<I, F>(&mut self, iter: I, weight_sampler: F) -> Option<I::Item>
+    where
+        I: IntoIterator,
+        F: Fn(&I::Item) -> f64,
+    {
+        let rng = self.get_mut();
+
+        iter.into_iter()
+            .filter_map(|item| {
+                let weight = weight_sampler(&item);
+
+                (weight > 0.0 && weight.is_finite()).then(|| {
+                    // Equivalent to the u^(1/w) key of A-Res, but in log space in order
+                    // to remain numerically stable with very small or large weights.
+                    let key = (1.0 - rng.f64()).ln() / weight;
+
+                    (key, item)
+                })
+            })
+            .fold(
+                None,
+                |chosen: Option<(f64, I::Item)>, (key, item)| match chosen {
+                    Some((best, _)) if best >= key => chosen,
+                    _ => Some((key, item)),
+                },
+            )
+            .map(|(_, item)| item)
+    }
+
+    /// Returns a random, fully opaque `Color` in sRGB colorspace, with each of
+    /// the red, green and blue channels drawn uniformly between `0.0` and `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let color = rng.random_color();
+    ///
+    /// assert_eq!(color.a(), 1.0);
+    /// ```
+    #[cfg(feature = "bevy_color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_color")))]
+    #[inline]
+    fn random_color(&mut self) -> Color {
+        let rng = self.get_mut();
+
+        Color::rgb(rng.f32(), rng.f32(), rng.f32())
+    }
+
+    /// Returns a random, fully opaque `Color` in HSL representation, with only the hue
+    /// being randomised. Keeping the saturation and lightness fixed yields colors that
+    /// sit well together, which is useful for generating palettes.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let palette: Vec<Color> = (0..5).map(|_| rng.random_color_hsl(0.7, 0.5)).collect();
+    ///
+    /// assert_eq!(palette.len(), 5);
+    /// ```
+    #[cfg(feature = "bevy_color")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_color")))]
+    #[inline]
+    fn random_color_hsl(&mut self, saturation: f32, lightness: f32) -> Color {
+        Color::hsl(self.f32() * 360.0, saturation, lightness)
+    }
+
+    /// Returns a random unit length [`Vec2`], uniformly distributed around the unit circle.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let direction = rng.unit_vec2();
+    ///
+    /// assert!(direction.is_normalized());
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn unit_vec2(&mut self) -> Vec2 {
+        let (sin, cos) = (TAU * self.f32()).sin_cos();
+
+        Vec2::new(cos, sin)
+    }
+
+    /// Returns a random unit length [`Vec3`], uniformly distributed over the surface
+    /// of the unit sphere. Picks a height along the z axis and an angle around it, which
+    /// avoids both the bias towards the corners of normalising a random point in a cube
+    /// and the need for rejection sampling.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let direction = rng.unit_vec3();
+    ///
+    /// assert!(direction.is_normalized());
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn unit_vec3(&mut self) -> Vec3 {
+        let z = 2.0 * self.f32() - 1.0;
+        let (sin, cos) = (TAU * self.f32()).sin_cos();
+        let radius = (1.0 - z * z).sqrt();
+
+        Vec3::new(radius * cos, radius * sin, z)
+    }
+
+    /// Returns a random, uniformly distributed rotation as a unit [`Quat`], using
+    /// [Shoemake's method](https://doi.org/10.1016/B978-0-08-050755-2.50036-1) from
+    /// three uniform draws.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let rotation = rng.random_quat();
+    ///
+    /// assert!(rotation.is_normalized());
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn random_quat(&mut self) -> Quat {
+        let rng = self.get_mut();
+
+        let u = rng.f32();
+        let (sin_1, cos_1) = (TAU * rng.f32()).sin_cos();
+        let (sin_2, cos_2) = (TAU * rng.f32()).sin_cos();
+
+        let lower = (1.0 - u).sqrt();
+        let upper = u.sqrt();
+
+        Quat::from_xyzw(lower * sin_1, lower * cos_1, upper * sin_2, upper * cos_2)
+    }
+
+    /// Returns a random point uniformly distributed within the rectangle spanning from
+    /// `min` to `max`, such as for picking spawn positions within an area.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let position = rng.random_in_rect(Vec2::new(-10.0, 0.0), Vec2::new(10.0, 5.0));
+    ///
+    /// assert!((-10.0..=10.0).contains(&position.x));
+    /// assert!((0.0..=5.0).contains(&position.y));
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn random_in_rect(&mut self, min: Vec2, max: Vec2) -> Vec2 {
+        let rng = self.get_mut();
+
+        min + (max - min) * Vec2::new(rng.f32(), rng.f32())
+    }
+
+    /// Returns a random point uniformly distributed over the area of the circle with the
+    /// given `center` and `radius`. The distance from the center is the square root of a
+    /// uniform draw, as drawing it directly would cluster points around the center.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let position = rng.random_in_circle(Vec2::new(5.0, 5.0), 2.0);
+    ///
+    /// assert!(position.distance(Vec2::new(5.0, 5.0)) <= 2.0);
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn random_in_circle(&mut self, center: Vec2, radius: f32) -> Vec2 {
+        let distance = radius * self.f32().sqrt();
+
+        center + self.unit_vec2() * distance
+    }
+
+    /// Returns a random point uniformly distributed along the edge of the circle with the
+    /// given `center` and `radius`.
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn random_on_circle(&mut self, center: Vec2, radius: f32) -> Vec2 {
+        center + self.unit_vec2() * radius
+    }
+
+    /// Returns a random point uniformly distributed within the axis-aligned bounding box
+    /// spanning from `min` to `max`. The 3D counterpart to [`DelegatedRng::random_in_rect`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let position = rng.random_in_aabb(Vec3::ZERO, Vec3::new(4.0, 2.0, 8.0));
+    ///
+    /// assert!(position.cmpge(Vec3::ZERO).all());
+    /// assert!(position.cmple(Vec3::new(4.0, 2.0, 8.0)).all());
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn random_in_aabb(&mut self, min: Vec3, max: Vec3) -> Vec3 {
+        let rng = self.get_mut();
+
+        min + (max - min) * Vec3::new(rng.f32(), rng.f32(), rng.f32())
+    }
+
+    /// Returns a random point uniformly distributed over the volume of the sphere with the
+    /// given `center` and `radius`. The distance from the center is the cube root of a
+    /// uniform draw, as drawing it directly would cluster points around the center.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let position = rng.random_in_sphere(Vec3::ONE, 2.0);
+    ///
+    /// assert!(position.distance(Vec3::ONE) <= 2.0);
+    /// ```
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn random_in_sphere(&mut self, center: Vec3, radius: f32) -> Vec3 {
+        let distance = radius * self.f32().cbrt();
+
+        center + self.unit_vec3() * distance
+    }
+
+    /// Returns a random point uniformly distributed over the surface of the sphere with
+    /// the given `center` and `radius`.
+    #[cfg(feature = "bevy_math")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bevy_math")))]
+    #[inline]
+    fn random_on_sphere(&mut self, center: Vec3, radius: f32) -> Vec3 {
+        center + self.unit_vec3() * radius
+    }
+
+    /// Parses and rolls standard dice notation, such as `"3d6+2"` or `"4d8-1+1d4"`,
+    /// returning the total. Notation is made up of `NdM` dice terms (rolling `N` dice
+    /// with `M` sides each, with `N` defaulting to one when left out) and flat integer
+    /// modifiers, separated by `+` or `-`. Whitespace is ignored. Each dice term rolls
+    /// between 1 and 1000 dice, so that notation supplied by users can't take arbitrarily
+    /// long to roll.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DiceParseError`] if the notation is malformed, in which case the
+    /// RNG state is left untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let damage = rng.roll("2d6+3").unwrap();
+    ///
+    /// assert!((5..=15).contains(&damage));
+    /// assert_eq!(rng.roll("3x6"), Err(DiceParseError::InvalidModifier));
+    /// ```
+    fn roll(&mut self, notation: &str) -> Result<i64, DiceParseError> {
+        let terms = crate::dice::parse(notation)?;
+        let rng = self.get_mut();
+
+        Ok(terms.iter().map(|term| term.roll(rng)).sum())
+    }
+
+    /// Returns `true` with a probability of one in `n`, such as for a 1 in 20 loot drop.
+    /// Uses an integer range draw rather than a float comparison, so the probability
+    /// is exact without any rounding bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// if rng.one_in(20) {
+    ///     println!("Rare drop!");
+    /// }
+    ///
+    /// assert!(rng.one_in(1));
+    /// ```
+    #[inline]
+    fn one_in(&mut self, n: u64) -> bool {
+        self.chance_in(1, n)
+    }
+
+    /// Returns `true` with a probability of `numerator` in `denominator`, such as 3 in 8.
+    /// Uses an integer range draw rather than a float comparison, so the probability
+    /// is exact without any rounding bias. Always returns `true` if `numerator` is greater
+    /// than or equal to `denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    #[inline]
+    fn chance_in(&mut self, numerator: u64, denominator: u64) -> bool {
+        assert!(denominator > 0, "denominator must be greater than zero");
+
+        self.get_mut().u64(..denominator) < numerator
+    }
+
+    /// Returns `true` or `false` with odds of `true_weight` to `false_weight`, such as 3 to 1
+    /// for a 75% chance of `true`. Computed purely with integer math as a
+    /// [`DelegatedRng::chance_in`] draw, so results are bit-identical across platforms,
+    /// unlike the float comparison of [`DelegatedRng::chance`]. This makes it suitable for
+    /// lockstep networking. A zero weight on either side makes the outcome certain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if both weights are zero.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let critical_hit = rng.bool_ratio(1, 4);
+    ///
+    /// assert!(rng.bool_ratio(1, 0));
+    /// assert!(!rng.bool_ratio(0, 1));
+    /// ```
+    #[inline]
+    fn bool_ratio(&mut self, true_weight: u32, false_weight: u32) -> bool {
+        assert!(
+            true_weight > 0 || false_weight > 0,
+            "at least one of the weights must be greater than zero"
+        );
+
+        self.chance_in(
+            u64::from(true_weight),
+            u64::from(true_weight) + u64::from(false_weight),
+        )
+    }
+
+    /// Returns `true` with a probability of `percent` in 100, such as for a 15% drop rate
+    /// specified by a designer. Computed purely with integer math as a
+    /// [`DelegatedRng::chance_in`] draw, so the probability is exact and results are
+    /// bit-identical across platforms. Always returns `true` if `percent` is 100 or more, and
+    /// `false` if it is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// if rng.chance_percent(15) {
+    ///     println!("Dropped a potion!");
+    /// }
+    ///
+    /// assert!(rng.chance_percent(100));
+    /// assert!(!rng.chance_percent(0));
+    /// ```
+    #[inline]
+    fn chance_percent(&mut self, percent: u8) -> bool {
+        self.chance_in(u64::from(percent), 100)
+    }
+
+    /// Returns either `-1` or `1` with equal probability, as any numeric type that can be
+    /// converted from an `i8`, such as `i32` or `f32`. Useful for flipping a direction or a
+    /// velocity at random. Draws the same `bool` as [`DelegatedRng::bool`], with `true`
+    /// mapping to `1`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let speed = 4.5 * rng.sign::<f32>();
+    /// let step: i32 = rng.sign();
+    ///
+    /// assert_eq!(speed.abs(), 4.5);
+    /// assert!(step == -1 || step == 1);
+    /// ```
+    #[inline]
+    fn sign<T: From<i8>>(&mut self) -> T {
+        T::from(if self.bool() { 1 } else { -1 })
+    }
+
+    /// Samples a random index from a slice of weights, with each index being chosen with
+    /// a probability proportional to its weight. Useful for looking up parallel arrays,
+    /// such as weights in one `Vec` and sprites in another. Negative and `NaN` weights are
+    /// clamped to zero, so those indices are never chosen. Returns `None` if the slice is
+    /// empty, or if no weight is greater than zero. As no `usize` values are drawn, the
+    /// selection is the same on 32-bit and 64-bit platforms.
+    ///
+    /// If any weight is infinite, only the infinitely weighted indices are chosen, with the
+    /// first of them always being picked. Finite weights that would overflow when summed are
+    /// normalised by the largest weight first, so they are still chosen proportionally.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let sprites = ["common", "uncommon", "rare"];
+    /// let weights = [70.0, 25.0, 5.0];
+    ///
+    /// let index = rng.weighted_index(&weights).unwrap();
+    ///
+    /// println!("Spawned a {} sprite", sprites[index]);
+    /// ```
+    #[inline]
+    fn weighted_index(&mut self, weights: &[f64]) -> Option<usize> {
+        let clamp = |weight: f64| if weight > 0.0 { weight } else { 0.0 };
+
+        let max = weights.iter().copied().map(clamp).fold(0.0, f64::max);
+
+        if max <= 0.0 {
+            return None;
+        }
+
+        if max.is_infinite() {
+            return weights.iter().position(|&weight| weight == f64::INFINITY);
+        }
+
+        let mut total: f64 = weights.iter().copied().map(clamp).sum();
+        let mut scale = 1.0;
+
+        // Normalising by the largest weight keeps finite weights from overflowing the total
+        if total.is_infinite() {
+            scale = max.recip();
+            total = weights.iter().map(|&weight| clamp(weight) * scale).sum();
+        }
+
+        let mut target = self.get_mut().f64() * total;
+        let mut last = None;
+
+        for (index, weight) in weights
+            .iter()
+            .map(|&weight| clamp(weight) * scale)
+            .enumerate()
+        {
+            if weight > 0.0 {
+                if target < weight {
+                    return Some(index);
+                }
+
+                target -= weight;
+                last = Some(index);
+            }
+        }
+
+        // Accumulated rounding error can push the target past the final weight
+        last
+    }
+
+    /// Generates a random integer within the given range, for any integer type implementing
+    /// [`SampleInteger`]. Dispatches to the matching [`TurboRand`] method, such as
+    /// [`TurboRand::u32`], so it is useful for writing code that is generic over integer widths.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// fn roll_stat<T: SampleInteger + From<u8>>(rng: &mut RngComponent) -> T {
+    ///     rng.gen_range(T::from(3)..=T::from(18))
+    /// }
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let strength: u16 = roll_stat(&mut rng);
+    /// let wisdom: i64 = roll_stat(&mut rng);
+    ///
+    /// assert!((3..=18).contains(&strength));
+    /// assert!((3..=18).contains(&wisdom));
+    /// ```
+    #[inline]
+    fn gen_range<T: SampleInteger>(&mut self, range: impl RangeBounds<T>) -> T {
+        T::sample_range(self.get_mut(), range)
+    }
+
+    /// Generates a string of `len` characters, each drawn uniformly from the characters
+    /// of `alphabet`. Sampling is done over `char`s rather than bytes, so multi-byte UTF-8
+    /// alphabets are handled correctly. Returns an empty string if `alphabet` is empty.
+    /// Characters are selected the same way on 32-bit and 64-bit platforms.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let room_code = rng.random_string(6, "ABCDEFGHJKLMNPQRSTUVWXYZ23456789");
+    ///
+    /// assert_eq!(room_code.len(), 6);
+    /// ```
+    #[inline]
+    fn random_string(&mut self, len: usize, alphabet: &str) -> String {
+        let alphabet: Vec<char> = alphabet.chars().collect();
+        let rng: &Self::Source = self.get_mut();
+
+        if alphabet.is_empty() {
+            return String::new();
+        }
+
+        (0..len)
+            .filter_map(|_| portable_sample(rng, &alphabet))
+            .collect()
+    }
+
+    /// Runs `f` against a copy of the current RNG source, returning its result without
+    /// advancing the state of the [`DelegatedRng`]. Useful for previewing upcoming draws,
+    /// such as for debugging or showing the outcome of a gameplay choice ahead of time.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let preview = rng.peek(|rng| rng.u32(1..=6));
+    ///
+    /// assert_eq!(preview, rng.u32(1..=6));
+    /// ```
+    #[inline]
+    fn peek<T, F>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&Self::Source) -> T,
+    {
+        f(&self.get_mut().clone())
+    }
+
+    /// Returns the next `u64` that would be generated, without advancing the state of
+    /// the [`DelegatedRng`].
+    #[inline]
+    fn peek_u64(&mut self) -> u64 {
+        self.peek(GenCore::gen_u64)
+    }
+
+    /// Returns an exponentially distributed `f64` value with the given rate `lambda`,
+    /// such as the time until the next event when events occur `lambda` times per unit
+    /// of time on average. The mean of the distribution is `1 / lambda`. Uses inverse
+    /// transform sampling on top of a uniform [`TurboRand::f64`] draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lambda` is not positive and finite.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// // On average, an enemy spawns every half a second
+    /// let next_spawn = rng.exponential(2.0);
+    ///
+    /// assert!(next_spawn >= 0.0);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn exponential(&mut self, lambda: f64) -> f64 {
+        assert!(
+            lambda > 0.0 && lambda.is_finite(),
+            "lambda must be a finite, positive value, received {}",
+            lambda
+        );
+
+        -(1.0 - self.get_mut().f64()).ln() / lambda
+    }
+
+    /// Returns a Poisson distributed `u64` value with the given mean `lambda`, such as
+    /// the number of events occurring in a unit of time when they occur `lambda` times
+    /// per unit of time on average.
+    ///
+    /// For `lambda` below 10, Knuth's multiplication method is used, which draws a number
+    /// of uniform values proportional to `lambda`. For larger `lambda`, Hörmann's
+    /// transformed rejection with squeeze (PTRS) is used instead, which needs only a few
+    /// draws on average no matter how large `lambda` is. Both methods are exact, rather
+    /// than approximations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lambda` is negative or not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// // On average, 3 meteors fall per wave
+    /// let meteors = rng.poisson(3.0);
+    ///
+    /// println!("{} meteors incoming!", meteors);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn poisson(&mut self, lambda: f64) -> u64 {
+        assert!(
+            lambda >= 0.0 && lambda.is_finite(),
+            "lambda must be a finite, non-negative value, received {}",
+            lambda
+        );
+
+        let rng = self.get_mut();
+
+        if lambda == 0.0 {
+            0
+        } else if lambda < crate::distributions::POISSON_PTRS_THRESHOLD {
+            crate::distributions::poisson_knuth(rng, lambda)
+        } else {
+            crate::distributions::poisson_ptrs(rng, lambda)
+        }
+    }
+
+    /// Returns a triangularly distributed `f64` value between `min` and `max`, peaking at
+    /// `mode`, using the inverse CDF over a single uniform [`TurboRand::f64`] draw. Useful
+    /// for designer defined spreads, such as weapon damage with a minimum, a most likely
+    /// and a maximum value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the values are not finite, or if `min <= mode <= max` does not hold.
+    /// Values are never clamped, so invalid parameters are caught early.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let damage = rng.triangular(10.0, 30.0, 15.0);
+    ///
+    /// assert!((10.0..=30.0).contains(&damage));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn triangular(&mut self, min: f64, max: f64, mode: f64) -> f64 {
+        assert!(
+            min.is_finite() && max.is_finite() && mode.is_finite(),
+            "min, max and mode must be finite, received {}, {} and {}",
+            min,
+            max,
+            mode
+        );
+        assert!(
+            min <= mode && mode <= max,
+            "min <= mode <= max must hold, received {}, {} and {}",
+            min,
+            max,
+            mode
+        );
+
+        let value = self.get_mut().f64();
+        let range = max - min;
+
+        if range == 0.0 {
+            return min;
+        }
+
+        if value < (mode - min) / range {
+            min + (value * range * (mode - min)).sqrt()
+        } else {
+            max - ((1.0 - value) * range * (max - mode)).sqrt()
+        }
+    }
+
+    /// Samples up to `amount` distinct items from a slice using a weight for each item,
+    /// without replacement. Uses the A-ExpJ weighted reservoir sampling algorithm from
+    /// [Efraimidis & Spirakis](https://doi.org/10.1016/j.ipl.2005.11.003), so only a
+    /// fraction of the items need a new random draw.
+    ///
+    /// Like [`DelegatedRng::sample_weighted_iter`], weights only need to be relative to each
+    /// other. Items with a weight that is zero, negative or not finite are only selected once
+    /// every validly weighted item has been, in which case they are picked uniformly, so
+    /// all-zero weights behave like [`DelegatedRng::sample_multiple`]. If `amount` is equal to
+    /// or larger than the length of the slice, all items are returned. Items are always
+    /// returned in the order they appear in the slice rather than the order they were
+    /// selected in, so lists built from the result stay stable, such as for display in a UI.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let loot = [("sword", 1.0), ("potion", 10.0), ("gold", 20.0), ("gem", 0.5)];
+    ///
+    /// let drops = rng.weighted_sample_multiple(&loot, 2, |&(_, weight)| weight);
+    ///
+    /// assert_eq!(drops.len(), 2);
+    /// assert_ne!(drops[0], drops[1]);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn weighted_sample_multiple<'a, T, F>(
+        &mut self,
+        list: &'a [T],
+        amount: usize,
+        weight_sampler: F,
+    ) -> Vec<&'a T>
+    where
+        F: Fn(&'a T) -> f64,
+    {
+        if amount >= list.len() {
+            return list.iter().collect();
+        }
+
+        let rng = self.get_mut();
+        let weights: Vec<f64> = list.iter().map(weight_sampler).collect();
+        let mut indices = crate::distributions::weighted_reservoir(rng, &weights, amount);
+
+        if indices.len() < amount {
+            let unweighted: Vec<usize> = weights
+                .iter()
+                .enumerate()
+                .filter(|&(_, &weight)| !(weight > 0.0 && weight.is_finite()))
+                .map(|(index, _)| index)
+                .collect();
+
+            indices.extend(
+                rng.sample_multiple(&unweighted, amount - indices.len())
+                    .into_iter()
+                    .copied(),
+            );
+        }
+
+        indices.sort_unstable();
+
+        indices.into_iter().map(|index| &list[index]).collect()
+    }
+
+    /// Returns a random index into a slice of length `len`, or `None` if `len` is zero.
+    /// A shorthand for `rng.usize(..len)` that doesn't panic on empty slices.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let spawn_points = [(0, 0), (5, 3), (8, 1)];
+    ///
+    /// let index = rng.index(spawn_points.len()).unwrap();
+    ///
+    /// assert!(index < spawn_points.len());
+    /// assert_eq!(rng.index(0), None);
+    /// ```
+    #[inline]
+    fn index(&mut self, len: usize) -> Option<usize> {
+        (len > 0).then(|| self.get_mut().usize(..len))
+    }
+
+    /// Returns `count` distinct random indices into a slice of length `len`, in random
+    /// order. If `count` is larger than `len`, all `len` indices are returned, shuffled.
+    /// Uses a partial Fisher-Yates shuffle, so it allocates `len` indices up front.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let indices = rng.indices(10, 3);
+    ///
+    /// assert_eq!(indices.len(), 3);
+    /// assert!(indices.iter().all(|&index| index < 10));
+    /// ```
+    fn indices(&mut self, len: usize, count: usize) -> Vec<usize> {
+        let rng = self.get_mut();
+        let count = count.min(len);
+        let mut indices: Vec<usize> = (0..len).collect();
+
+        for position in 0..count {
+            let swap = rng.u64(position as u64..len as u64) as usize;
+
+            indices.swap(position, swap);
+        }
+
+        indices.truncate(count);
+
+        indices
+    }
+
+    /// Returns an endless iterator that lazily yields values generated by `generator`
+    /// from the internal [`TurboRand`] source. The iterator borrows the RNG mutably for as
+    /// long as it lives, avoiding the need to shadow the RNG with [`DelegatedRng::get_mut`]
+    /// in order to capture it within [`core::iter::repeat_with`].
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let rolls: Vec<u8> = rng.iter_with(|rng| rng.u8(1..=6)).take(4).collect();
+    ///
+    /// assert_eq!(rolls.len(), 4);
+    /// ```
+    #[inline]
+    fn iter_with<T, F>(&mut self, generator: F) -> RandomIter<'_, Self::Source, F>
+    where
+        F: FnMut(&Self::Source) -> T,
+    {
+        RandomIter {
+            rng: self.get_mut(),
+            generator,
+        }
+    }
+
+    /// Returns an endless iterator of random `f32` values between `0.0` and `1.0`. See
+    /// [`DelegatedRng::iter_with`] for more details.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let noise: Vec<f32> = rng.iter_f32().take(10).collect();
+    ///
+    /// assert_eq!(noise.len(), 10);
+    /// ```
+    #[inline]
+    fn iter_f32(&mut self) -> RandomValues<'_, Self::Source, f32> {
+        self.iter_with(TurboRand::f32)
+    }
+
+    /// Returns an endless iterator of random `f64` values between `0.0` and `1.0`. See
+    /// [`DelegatedRng::iter_with`] for more details.
+    #[inline]
+    fn iter_f64(&mut self) -> RandomValues<'_, Self::Source, f64> {
+        self.iter_with(TurboRand::f64)
+    }
+
+    /// Returns an endless iterator of random `u32` values. See [`DelegatedRng::iter_with`]
+    /// for more details.
+    #[inline]
+    fn iter_u32(&mut self) -> RandomValues<'_, Self::Source, u32> {
+        self.iter_with(|rng| rng.u32(..))
+    }
+
+    /// Returns an endless iterator of random `u64` values. See [`DelegatedRng::iter_with`]
+    /// for more details.
+    #[inline]
+    fn iter_u64(&mut self) -> RandomValues<'_, Self::Source, u64> {
+        self.iter_with(|rng| rng.u64(..))
+    }
+
+    /// Returns an endless iterator of random `bool` values. See [`DelegatedRng::iter_with`]
+    /// for more details.
+    #[inline]
+    fn iter_bool(&mut self) -> RandomValues<'_, Self::Source, bool> {
+        self.iter_with(TurboRand::bool)
+    }
+
+    /// Returns an iterator that endlessly yields random items from a slice of values, as a
+    /// reusable stream of [`DelegatedRng::sample_portable`] picks that composes with other
+    /// iterator adaptors such as `take`. Items are picked independently, so they can repeat.
+    /// The iterator borrows the RNG mutably for as long as it lives, while the items it yields
+    /// borrow only the slice, so they can outlive the iterator. Yields nothing if the slice is
+    /// empty.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let notes = ["C", "D", "E", "G", "A"];
+    ///
+    /// let melody: Vec<&str> = rng.choices(&notes).take(8).copied().collect();
+    ///
+    /// assert_eq!(melody.len(), 8);
+    /// assert!(melody.iter().all(|note| notes.contains(note)));
+    /// ```
+    #[inline]
+    fn choices<'a, 'b, T>(&'a mut self, list: &'b [T]) -> RandomChoices<'a, 'b, Self::Source, T> {
+        RandomChoices {
+            rng: self.get_mut(),
+            list,
+        }
+    }
+
+    /// Samples a random item from a slice of values, returning it along with its index in
+    /// the slice, or `None` if the slice is empty. Useful for when the position of the item
+    /// is needed as well, such as for highlighting the chosen entry of a UI list.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let options = ["attack", "defend", "flee"];
+    ///
+    /// let (index, choice) = rng.sample_indexed(&options).unwrap();
+    ///
+    /// assert_eq!(options[index], *choice);
+    /// ```
+    #[inline]
+    fn sample_indexed<'a, T>(&mut self, list: &'a [T]) -> Option<(usize, &'a T)> {
+        self.index(list.len()).map(|index| (index, &list[index]))
+    }
+
+    /// Returns a clone of a random item from a slice of values, or the given `default` if
+    /// the slice is empty. A shorthand for `rng.sample(list).cloned().unwrap_or(default)`,
+    /// drawing the same item as [`DelegatedRng::sample`] for the same RNG state.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let greetings = ["hello", "hi", "hey"];
+    /// let none: [&str; 0] = [];
+    ///
+    /// assert!(greetings.contains(&rng.sample_or(&greetings, "...")));
+    /// assert_eq!(rng.sample_or(&none, "..."), "...");
+    /// ```
+    #[inline]
+    fn sample_or<T: Clone>(&mut self, list: &[T], default: T) -> T {
+        self.sample(list).cloned().unwrap_or(default)
+    }
+
+    /// Shuffles a slice so that items with higher weights tend to appear earlier, rather
+    /// than uniformly like [`DelegatedRng::shuffle`]. Uses the exponential key ordering from
+    /// [Efraimidis & Spirakis](https://doi.org/10.1016/j.ipl.2005.11.003), sorting the items
+    /// by the same `u^(1/w)` keys as [`DelegatedRng::sample_weighted_iter`]. The first item
+    /// is therefore chosen with a probability proportional to its weight, the second among
+    /// the remaining items in the same way, and so on.
+    ///
+    /// Weights only need to be relative to each other. Items with a weight that is zero,
+    /// negative or not finite are placed after all validly weighted items, in a uniformly
+    /// random order.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let mut playlist = [("favourite", 10.0), ("liked", 5.0), ("skipped", 0.5)];
+    ///
+    /// rng.weighted_shuffle(&mut playlist, |&(_, weight)| weight);
+    ///
+    /// assert_eq!(playlist.len(), 3);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn weighted_shuffle<T, F>(&mut self, list: &mut [T], weight_sampler: F)
+    where
+        F: Fn(&T) -> f64,
+    {
+        let rng = self.get_mut();
+
+        let keys: Vec<(f64, u64)> = list
+            .iter()
+            .map(|item| {
+                let weight = weight_sampler(item);
+
+                if weight > 0.0 && weight.is_finite() {
+                    ((1.0 - rng.f64()).ln() / weight, 0)
+                } else {
+                    (f64::NEG_INFINITY, rng.u64(..))
+                }
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..list.len()).collect();
+
+        order.sort_unstable_by(|&a, &b| {
+            keys[b]
+                .0
+                .total_cmp(&keys[a].0)
+                .then_with(|| keys[b].1.cmp(&keys[a].1))
+        });
+
+        // Apply the permutation in place by walking its cycles, so that `T` doesn't need
+        // to be `Clone`.
+        for start in 0..order.len() {
+            let mut current = start;
+
+            loop {
+                let source = order[current];
+
+                order[current] = current;
+
+                if source == start {
+                    break;
+                }
+
+                list.swap(current, source);
+                current = source;
+            }
+        }
+    }
+
+    /// Draws a value from a custom distribution implementing [`SampleFromRng`], so that
+    /// user defined distributions can be sampled the same way as the built-in ones.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let die = Die(6);
+    ///
+    /// let roll = rng.draw(&die);
+    ///
+    /// assert!((1..=6).contains(&roll));
+    /// # struct Die(u32);
+    /// # impl SampleFromRng<u32> for Die {
+    /// #     fn sample(&self, rng: &mut impl DelegatedRng) -> u32 {
+    /// #         rng.u32(1..=self.0)
+    /// #     }
+    /// # }
+    /// ```
+    #[inline]
+    fn draw<T, D>(&mut self, distribution: &D) -> T
+    where
+        Self: Sized,
+        D: SampleFromRng<T> + ?Sized,
+    {
+        distribution.sample(self)
+    }
+
+    /// Repeatedly generates candidates with `generator` until one passes `predicate`,
+    /// returning the first passing value, or `None` if none passed within `max_attempts`
+    /// attempts. Standardises rejection sampling loops, such as finding a valid spawn
+    /// position, while bounding them so that an impossible predicate can't loop forever.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// // Pick a tile that isn't on the blocked centre row
+    /// let tile = rng.retry_until(
+    ///     |rng| (rng.u32(0..10), rng.u32(0..10)),
+    ///     |&(_, y)| y != 5,
+    ///     100,
+    /// );
+    ///
+    /// assert!(tile.is_some());
+    /// assert_eq!(rng.retry_until(|rng| rng.u32(0..10), |&n| n > 10, 100), None);
+    /// ```
+    #[inline]
+    fn retry_until<T, F, P>(
+        &mut self,
+        mut generator: F,
+        mut predicate: P,
+        max_attempts: usize,
+    ) -> Option<T>
+    where
+        Self: Sized,
+        F: FnMut(&mut Self) -> T,
+        P: FnMut(&T) -> bool,
+    {
+        (0..max_attempts)
+            .map(|_| generator(self))
+            .find(|candidate| predicate(candidate))
+    }
+
+    /// Generates a random `f64` value within the given inclusive range, where both endpoints
+    /// can be produced. Draws a value from the closed unit interval `[0, 1]` with 53 bits of
+    /// precision, then interpolates between the endpoints in a way that can't overflow, even
+    /// for ranges as wide as `f64::MIN..=f64::MAX`. A zero-width range always returns its
+    /// single value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either endpoint is not finite, or if the range is inverted, such as
+    /// `1.0..=0.0`. Inverted ranges are not swapped, so mistakes are caught early.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let temperature = rng.f64_range(-10.0..=35.0);
+    ///
+    /// assert!((-10.0..=35.0).contains(&temperature));
+    /// ```
+    #[inline]
+    fn f64_range(&mut self, range: RangeInclusive<f64>) -> f64 {
+        let (start, end) = range.into_inner();
+
+        assert!(
+            start.is_finite() && end.is_finite(),
+            "range bounds must be finite, received {}..={}",
+            start,
+            end
+        );
+        assert!(
+            start <= end,
+            "range must not be inverted, received {}..={}",
+            start,
+            end
+        );
+
+        const MAX: u64 = (1 << f64::MANTISSA_DIGITS) - 1;
+
+        let unit = (self.get_mut().u64(..) >> (64 - f64::MANTISSA_DIGITS)) as f64 / MAX as f64;
+
+        (start * (1.0 - unit) + end * unit).clamp(start, end)
+    }
+
+    /// Generates a random `f32` value within the given inclusive range, where both endpoints
+    /// can be produced. The `f32` counterpart to [`DelegatedRng::f64_range`], drawing from
+    /// the closed unit interval with 24 bits of precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either endpoint is not finite, or if the range is inverted.
+    #[inline]
+    fn f32_range(&mut self, range: RangeInclusive<f32>) -> f32 {
+        let (start, end) = range.into_inner();
+
+        assert!(
+            start.is_finite() && end.is_finite(),
+            "range bounds must be finite, received {}..={}",
+            start,
+            end
+        );
+        assert!(
+            start <= end,
+            "range must not be inverted, received {}..={}",
+            start,
+            end
+        );
+
+        const MAX: u32 = (1 << f32::MANTISSA_DIGITS) - 1;
+
+        let unit = (self.get_mut().u32(..) >> (32 - f32::MANTISSA_DIGITS)) as f32 / MAX as f32;
+
+        (start * (1.0 - unit) + end * unit).clamp(start, end)
+    }
+
+    /// Perturbs a `f64` value by a uniformly random offset of up to `amount` in either
+    /// direction, returning a value within `base - amount..=base + amount`. Useful for adding
+    /// variation to animations, particles and other juicing effects. Drawn with
+    /// [`DelegatedRng::f64_range`], so both extremes can be produced, and an `amount` of zero
+    /// always returns `base`. Results are clamped to the finite range of `f64`, so offsets
+    /// that would overflow, such as `jitter_f64(f64::MAX, f64::MAX)`, stay finite.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` or `amount` is not finite, or if `amount` is negative.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let pitch = rng.jitter_f64(1.0, 0.1);
+    ///
+    /// assert!((0.9..=1.1).contains(&pitch));
+    /// ```
+    #[inline]
+    fn jitter_f64(&mut self, base: f64, amount: f64) -> f64 {
+        assert!(
+            base.is_finite() && amount.is_finite(),
+            "jitter base and amount must be finite, received {} and {}",
+            base,
+            amount
+        );
+        assert!(
+            amount >= 0.0,
+            "jitter amount must not be negative, received {}",
+            amount
+        );
+
+        // Finite inputs can still overflow the bounds, so keep them within the finite range
+        let start = (base - amount).max(f64::MIN);
+        let end = (base + amount).min(f64::MAX);
+
+        self.f64_range(start..=end)
+    }
+
+    /// Perturbs a `f32` value by a uniformly random offset of up to `amount` in either
+    /// direction. The `f32` counterpart to [`DelegatedRng::jitter_f64`], drawn with
+    /// [`DelegatedRng::f32_range`] and clamped to the finite range of `f32`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` or `amount` is not finite, or if `amount` is negative.
+    #[inline]
+    fn jitter_f32(&mut self, base: f32, amount: f32) -> f32 {
+        assert!(
+            base.is_finite() && amount.is_finite(),
+            "jitter base and amount must be finite, received {} and {}",
+            base,
+            amount
+        );
+        assert!(
+            amount >= 0.0,
+            "jitter amount must not be negative, received {}",
+            amount
+        );
+
+        // Finite inputs can still overflow the bounds, so keep them within the finite range
+        let start = (base - amount).max(f32::MIN);
+        let end = (base + amount).min(f32::MAX);
+
+        self.f32_range(start..=end)
+    }
+
+    /// Generates a random `f64` value within the open unit interval `(0, 1)`, which never
+    /// returns exactly `0.0` or `1.0`, unlike [`TurboRand::f64`] which can return `0.0`. Useful
+    /// for algorithms that would otherwise break on zero, such as taking the logarithm for
+    /// exponential sampling. Draws 52 bits, and returns the centre of the matching step of
+    /// width `2^-52`, so values are evenly spaced between exactly `2^-53` and `1 - 2^-53`.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let value = rng.f64_open();
+    ///
+    /// assert!(value > 0.0 && value < 1.0);
+    /// assert!(value.ln().is_finite());
+    /// ```
+    #[inline]
+    fn f64_open(&mut self) -> f64 {
+        const BITS: u32 = f64::MANTISSA_DIGITS - 1;
+
+        let step = (self.get_mut().u64(..) >> (64 - BITS)) as f64;
+
+        (step + 0.5) / (1_u64 << BITS) as f64
+    }
+
+    /// Generates a random `f32` value within the open unit interval `(0, 1)`. The `f32`
+    /// counterpart to [`DelegatedRng::f64_open`], drawing 23 bits, so values are evenly
+    /// spaced between exactly `2^-24` and `1 - 2^-24`.
+    #[inline]
+    fn f32_open(&mut self) -> f32 {
+        const BITS: u32 = f32::MANTISSA_DIGITS - 1;
+
+        let step = (self.get_mut().u32(..) >> (32 - BITS)) as f32;
+
+        (step + 0.5) / (1_u32 << BITS) as f32
+    }
+
+    /// Rolls a `u32` within the given range with advantage, drawing twice and returning
+    /// the higher of the two values, as with tabletop "advantage" rolls.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let attack = rng.u32_advantage(1..=20);
+    ///
+    /// assert!((1..=20).contains(&attack));
+    /// ```
+    #[inline]
+    fn u32_advantage(&mut self, range: impl RangeBounds<u32> + Clone) -> u32 {
+        self.advantage(range)
+    }
+
+    /// Rolls a `u32` within the given range with disadvantage, drawing twice and returning
+    /// the lower of the two values, as with tabletop "disadvantage" rolls.
+    #[inline]
+    fn u32_disadvantage(&mut self, range: impl RangeBounds<u32> + Clone) -> u32 {
+        self.disadvantage(range)
+    }
+
+    /// Rolls an integer within the given range with advantage, drawing twice and returning
+    /// the higher of the two values. The generic counterpart to
+    /// [`DelegatedRng::u32_advantage`], for any integer type implementing [`SampleInteger`].
+    #[inline]
+    fn advantage<T: SampleInteger>(&mut self, range: impl RangeBounds<T> + Clone) -> T {
+        self.best_of(2, range)
+    }
+
+    /// Rolls an integer within the given range with disadvantage, drawing twice and returning
+    /// the lower of the two values. The generic counterpart to
+    /// [`DelegatedRng::u32_disadvantage`], for any integer type implementing [`SampleInteger`].
+    #[inline]
+    fn disadvantage<T: SampleInteger>(&mut self, range: impl RangeBounds<T> + Clone) -> T {
+        let first = self.gen_range(range.clone());
+        let second = self.gen_range(range);
+
+        if second < first {
+            second
+        } else {
+            first
+        }
+    }
+
+    /// Draws `n` integers within the given range and returns the highest of them, for any
+    /// integer type implementing [`SampleInteger`]. Always draws exactly `n` values, so the
+    /// amount of RNG state consumed is predictable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// // Roll 4d6, keeping the highest die
+    /// let highest: u8 = rng.best_of(4, 1..=6);
+    ///
+    /// assert!((1..=6).contains(&highest));
+    /// ```
+    #[inline]
+    fn best_of<T: SampleInteger>(&mut self, n: u32, range: impl RangeBounds<T> + Clone) -> T {
+        assert!(n > 0, "best_of requires at least one draw");
+
+        let first = self.gen_range(range.clone());
+
+        (1..n).fold(first, |best, _| {
+            let value = self.gen_range(range.clone());
+
+            if value > best {
+                value
+            } else {
+                best
+            }
+        })
+    }
+
+    /// Creates a [`SeededState`] with a seed drawn from the RNG, for building `HashMap`s and
+    /// `HashSet`s that hash keys the same way every run, instead of depending on the
+    /// process-wide randomness of `RandomState`. As the seed comes from a single `u64` draw,
+    /// RNGs with the same state produce hashers that hash every key identically.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// let mut visited: HashSet<(i32, i32), _> = HashSet::with_hasher(rng.seeded_hasher());
+    ///
+    /// visited.insert((0, 0));
+    ///
+    /// assert!(visited.contains(&(0, 0)));
+    /// ```
+    #[inline]
+    fn seeded_hasher(&mut self) -> SeededState {
+        SeededState::with_seed(self.get_mut().gen_u64())
+    }
+
+    /// Returns a gamma distributed `f64` value with the given `shape` and `scale`, using
+    /// Marsaglia & Tsang's method, which takes only a few draws on average for any shape.
+    /// The mean of the distribution is `shape * scale`. Useful for positive, right skewed
+    /// values such as resource yields, where `shape` controls the skew and `scale` stretches
+    /// the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` or `scale` are not positive and finite.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// // Averages 6 ore per node, rarely much more
+    /// let ore = rng.gamma(3.0, 2.0);
+    ///
+    /// assert!(ore >= 0.0);
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn gamma(&mut self, shape: f64, scale: f64) -> f64 {
+        assert!(
+            shape > 0.0 && shape.is_finite(),
+            "shape must be a finite, positive value, received {}",
+            shape
+        );
+        assert!(
+            scale > 0.0 && scale.is_finite(),
+            "scale must be a finite, positive value, received {}",
+            scale
+        );
+
+        crate::distributions::gamma_marsaglia_tsang(self.get_mut(), shape) * scale
+    }
+
+    /// Returns a beta distributed `f64` value between 0 and 1 with the given `alpha` and
+    /// `beta` parameters, derived from two gamma distributed draws. The mean of the
+    /// distribution is `alpha / (alpha + beta)`, with larger parameters concentrating values
+    /// around it, and parameters below 1 pushing values towards 0 and 1 instead. Useful for
+    /// tuning probabilities themselves, such as skewing rarity rolls towards common results.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` or `beta` are not positive and finite.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_turborand::prelude::*;
+    ///
+    /// let mut rng = RngComponent::with_seed(12345);
+    ///
+    /// // Usually low, occasionally high
+    /// let rarity = rng.beta(2.0, 5.0);
+    ///
+    /// assert!((0.0..=1.0).contains(&rarity));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn beta(&mut self, alpha: f64, beta: f64) -> f64 {
+        assert!(
+            alpha > 0.0 && alpha.is_finite(),
+            "alpha must be a finite, positive value, received {}",
+            alpha
+        );
+        assert!(
+            beta > 0.0 && beta.is_finite(),
+            "beta must be a finite, positive value, received {}",
+            beta
+        );
+
+        let rng = self.get_mut();
+        let x = crate::distributions::gamma_marsaglia_tsang(rng, alpha);
+        let y = crate::distributions::gamma_marsaglia_tsang(rng, beta);
+
+        if x + y > 0.0 {
+            x / (x + y)
+        } else {
+            // Both draws underflowed, which only happens for tiny parameters, where nearly
+            // all of the distribution sits at either end
+            f64::from(u8::from(rng.chance(alpha / (alpha + beta))))
+        }
+    }
+}
+
+/// An endless iterator yielding values generated from a mutably borrowed [`TurboRand`]
+/// source. Created with [`DelegatedRng::iter_with`] and the other `iter_*` methods on
+/// [`DelegatedRng`].
+#[derive(Debug)]
+pub struct RandomIter<'a, S, F> {
+    rng: &'a S,
+    generator: F,
+}
+
+/// A [`RandomIter`] yielding values of type `T` from a plain generator function, as returned
+/// by [`DelegatedRng::iter_f32`] and the other typed `iter_*` methods.
+pub type RandomValues<'a, S, T> = RandomIter<'a, S, fn(&S) -> T>;
+
+impl<'a, S, T, F> Iterator for RandomIter<'a, S, F>
+where
+    F: FnMut(&S) -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.generator)(self.rng))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<'a, S, T, F> core::iter::FusedIterator for RandomIter<'a, S, F> where F: FnMut(&S) -> T {}
+
+/// An iterator endlessly yielding random items from a slice, using a mutably borrowed
+/// [`TurboRand`] source. Created with [`DelegatedRng::choices`].
+#[derive(Debug)]
+pub struct RandomChoices<'a, 'b, S, T> {
+    rng: &'a S,
+    list: &'b [T],
+}
+
+impl<'a, 'b, S: TurboRand, T> Iterator for RandomChoices<'a, 'b, S, T> {
+    type Item = &'b T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        portable_sample(self.rng, self.list)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.list.is_empty() {
+            (0, Some(0))
+        } else {
+            (usize::MAX, None)
+        }
+    }
+}
+
+impl<'a, 'b, S: TurboRand, T> core::iter::FusedIterator for RandomChoices<'a, 'b, S, T> {}
+
+/// A trait for custom distributions that generate values of type `T` from any
+/// [`DelegatedRng`], such as a [`GlobalRng`] or an [`RngComponent`]. Implementing it allows
+/// a distribution to be sampled with [`DelegatedRng::draw`], and to compose with other
+/// distributions by sampling them from within [`SampleFromRng::sample`].
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Rarity {
+///     Common,
+///     Rare,
+///     Legendary,
+/// }
+///
+/// /// A loot rarity curve, where each tier is `falloff` times less likely than the last.
+/// struct RarityCurve {
+///     falloff: u32,
+/// }
+///
+/// impl SampleFromRng<Rarity> for RarityCurve {
+///     fn sample(&self, rng: &mut impl DelegatedRng) -> Rarity {
+///         if !rng.one_in(u64::from(self.falloff)) {
+///             Rarity::Common
+///         } else if !rng.one_in(u64::from(self.falloff)) {
+///             Rarity::Rare
+///         } else {
+///             Rarity::Legendary
+///         }
+///     }
+/// }
+///
+/// let mut rng = RngComponent::with_seed(12345);
+///
+/// let rarity = rng.draw(&RarityCurve { falloff: 10 });
+///
+/// println!("Dropped a {:?} item", rarity);
+/// ```
+pub trait SampleFromRng<T> {
+    /// Generates a value from the distribution using the given RNG.
+    fn sample(&self, rng: &mut impl DelegatedRng) -> T;
+}
+
+/// A trait for types that can be picked at random in their entirety, such as enums
+/// selecting one of their variants. With the `derive` feature enabled, it can be derived
+/// for enums, which then select uniformly among their unit variants. Variants with fields
+/// are never selected.
+///
+/// The variant index is drawn as a `u64`, so the same RNG state selects the same variant
+/// on all platforms.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Direction {
+///     North,
+///     South,
+/// }
+///
+/// impl RandomVariant for Direction {
+///     fn random(rng: &mut impl DelegatedRng) -> Self {
+///         if rng.bool() {
+///             Self::North
+///         } else {
+///             Self::South
+///         }
+///     }
+/// }
+///
+/// let mut rng = RngComponent::with_seed(12345);
+///
+/// let direction = Direction::random(&mut rng);
+///
+/// println!("Heading {:?}", direction);
+/// ```
+pub trait RandomVariant: Sized {
+    /// Picks a random value of the type using the given RNG.
+    fn random(rng: &mut impl DelegatedRng) -> Self;
+}
+
+/// A trait for integer types that can be generated within a range by
+/// [`DelegatedRng::gen_range`], covering every integer width from `u8`/`i8` to
+/// `u128`/`i128`, as well as `usize`/`isize`.
+pub trait SampleInteger: Sized + Copy + PartialOrd {
+    /// Generates a value within the given range from a [`TurboRand`] source.
+    fn sample_range<R: TurboRand>(rng: &R, range: impl RangeBounds<Self>) -> Self;
+}
+
+macro_rules! impl_sample_integer {
+    ($($int:tt),*) => {
+        $(
+            impl SampleInteger for $int {
+                #[inline]
+                fn sample_range<R: TurboRand>(rng: &R, range: impl RangeBounds<Self>) -> Self {
+                    rng.$int(range)
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Reorders a slice in place according to a permutation returned by
+/// [`DelegatedRng::shuffle_with_indices`], so that `slice[i]` becomes the element originally
+/// at `permutation[i]`. Applying the permutation to a copy of the original slice reproduces
+/// the shuffled order exactly, without needing the RNG that shuffled it.
+///
+/// # Panics
+///
+/// Panics if the permutation is not the same length as the slice, or if it doesn't contain
+/// every index of the slice exactly once.
+///
+/// # Example
+/// ```
+/// use bevy_turborand::prelude::*;
+///
+/// let mut hand = ['a', 'b', 'c'];
+///
+/// apply_permutation(&mut hand, &[2, 0, 1]);
+///
+/// assert_eq!(hand, ['c', 'a', 'b']);
+/// ```
+pub fn apply_permutation<T>(slice: &mut [T], permutation: &[usize]) {
+    assert_eq!(
+        slice.len(),
+        permutation.len(),
+        "permutation must be the same length as the slice"
+    );
+
+    let mut pending = alloc::vec![false; permutation.len()];
+
+    for &index in permutation {
+        assert!(
+            index < pending.len() && !pending[index],
+            "permutation must contain every index of the slice exactly once"
+        );
+
+        pending[index] = true;
+    }
+
+    // Follow each cycle of the permutation, swapping every element into place once
+    for start in 0..slice.len() {
+        let mut current = start;
+
+        while pending[current] {
+            pending[current] = false;
+
+            let next = permutation[current];
+
+            if next == start {
+                break;
+            }
+
+            slice.swap(current, next);
+            current = next;
+        }
+    }
+}
+
+/// Fisher-Yates shuffle drawing `u64` indices, so the permutation doesn't depend on the
+/// platform's pointer width.
+#[inline]
+fn portable_shuffle<R: TurboRand, T>(rng: &R, slice: &mut [T]) {
+    for index in (1..slice.len()).rev() {
+        let swap = rng.u64(..=index as u64) as usize;
+
+        slice.swap(index, swap);
+    }
+}
+
+/// Samples a slice by drawing a `u64` index, so the selection doesn't depend on the
+/// platform's pointer width.
+#[inline]
+fn portable_sample<'a, R: TurboRand, T>(rng: &R, list: &'a [T]) -> Option<&'a T> {
+    match list.len() {
+        0 => None,
+        1 => list.first(),
+        len => list.get(rng.u64(..len as u64) as usize),
+    }
 }
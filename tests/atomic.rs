@@ -0,0 +1,70 @@
+#![cfg(feature = "atomic")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn assert_sync<T: Send + Sync>(_: &T) {}
+
+#[test]
+fn atomic_global_parallel_draws() {
+    let rng = AtomicGlobalRng::with_seed(12345);
+
+    assert_sync(&rng);
+
+    let mut draws: Vec<u64> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| (0..1_000).map(|_| rng.get().u64(..)).collect::<Vec<_>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    // No draw is lost or duplicated, so every value in the sequence is handed out once
+    let mut expected: Vec<u64> = {
+        let reference = AtomicGlobalRng::with_seed(12345);
+
+        (0..4_000).map(|_| reference.get().u64(..)).collect()
+    };
+
+    expected.sort_unstable();
+    draws.sort_unstable();
+
+    assert_eq!(draws, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn atomic_global_shared_access_in_systems() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new())
+        .add_system(|rng: Res<'_, AtomicGlobalRng>| {
+            rng.get().u32(..);
+        })
+        .add_system(|rng: Res<'_, AtomicGlobalRng>| {
+            rng.get().u32(..);
+        });
+
+    app.update();
+
+    assert!(app.world.contains_resource::<AtomicGlobalRng>());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn atomic_global_single_threaded_determinism() {
+    let mut rng_1 = AtomicGlobalRng::with_seed(12345);
+    let rng_2 = AtomicGlobalRng::with_seed(12345);
+
+    assert_eq!(rng_1.u64(..), rng_2.get().u64(..));
+    assert_eq!(rng_1.f32(), rng_2.get().f32());
+}
@@ -0,0 +1,125 @@
+#![cfg(all(feature = "serialize", feature = "wyrand"))]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_rng_bincode_round_trip() {
+    let mut rng = GlobalRng::with_seed(12345);
+
+    rng.u64(..);
+
+    let bytes = bincode::serialize(&rng).unwrap();
+
+    assert_eq!(bytes.len(), GlobalRng::SERIALIZED_SIZE);
+
+    let mut restored: GlobalRng = bincode::deserialize(&bytes).unwrap();
+
+    assert_eq!(restored, rng);
+    assert_eq!(restored.u64(..), rng.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rng_component_bincode_round_trip() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let bytes = bincode::serialize(&rng).unwrap();
+
+    assert_eq!(bytes.len(), RngComponent::SERIALIZED_SIZE);
+
+    let mut restored: RngComponent = bincode::deserialize(&bytes).unwrap();
+
+    assert_eq!(restored.u64(..), rng.u64(..));
+
+    // The encoding is the raw state, so it stays the same for the same state
+    assert_eq!(
+        bincode::serialize(&RngComponent::with_seed(12345)).unwrap(),
+        bytes
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn truncated_bincode_buffer_errors() {
+    let bytes = bincode::serialize(&GlobalRng::with_seed(12345)).unwrap();
+
+    for len in 0..bytes.len() {
+        assert!(bincode::deserialize::<GlobalRng>(&bytes[..len]).is_err());
+        assert!(bincode::deserialize::<RngComponent>(&bytes[..len]).is_err());
+    }
+}
+
+/// A `RngComponent::with_seed(12345)`, as serialized by `bincode` with `turborand` 0.8.
+const TURBORAND_08_BLOB: [u8; 8] = [0x73, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn recorded_legacy_blob_migrates() {
+    assert_eq!(
+        LegacyRngState::detect(&TURBORAND_08_BLOB),
+        Ok(LegacyRngState::Turborand08 { state: 24691 })
+    );
+
+    let mut migrated = RngComponent::migrate(&TURBORAND_08_BLOB).unwrap();
+    let mut expected = RngComponent::with_seed(12345);
+
+    assert_eq!(migrated, expected);
+    assert_eq!(migrated.u64(..), expected.u64(..));
+
+    // The blob is still the current layout, so it matches what gets serialized today
+    assert_eq!(
+        bincode::serialize(&RngComponent::with_seed(12345)).unwrap(),
+        TURBORAND_08_BLOB
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn versioned_state_blob_migrates() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    rng.u64(..);
+
+    let bytes = bincode::serialize(&SerializableRngState::from(&rng)).unwrap();
+
+    assert_eq!(
+        LegacyRngState::detect(&bytes),
+        Ok(LegacyRngState::Versioned {
+            version: SerializableRngState::VERSION,
+            state: 24691_u64.wrapping_add(0xa076_1d64_78bd_642f),
+        })
+    );
+
+    let mut migrated = RngComponent::migrate(&bytes).unwrap();
+
+    assert_eq!(migrated.u64(..), rng.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unknown_legacy_layouts_error() {
+    assert_eq!(
+        RngComponent::migrate(&[0; 5]),
+        Err(MigrateError::UnknownLayout(5))
+    );
+    assert_eq!(
+        RngComponent::migrate(&[]),
+        Err(MigrateError::UnknownLayout(0))
+    );
+
+    let mut future = [0; 12];
+
+    future[..4].copy_from_slice(&99_u32.to_le_bytes());
+
+    assert_eq!(
+        RngComponent::migrate(&future),
+        Err(MigrateError::UnsupportedVersion(99))
+    );
+}
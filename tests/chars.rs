@@ -0,0 +1,78 @@
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const TRIALS: usize = 5_000;
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hex_digits_in_set() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let digits: Vec<char> = (0..TRIALS).map(|_| rng.digit(16)).collect();
+
+    assert!(digits.iter().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+    assert!(digits.contains(&'0') && digits.contains(&'f'));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn alphanumeric_and_ascii_in_set() {
+    let mut rng = GlobalRng::with_seed(12345);
+
+    assert!((0..TRIALS).all(|_| rng.alphanumeric().is_ascii_alphanumeric()));
+
+    let ascii: Vec<char> = (0..TRIALS).map(|_| rng.ascii()).collect();
+
+    assert!(ascii.iter().all(|c| (' '..='~').contains(c)));
+    assert!(ascii.contains(&' ') && ascii.contains(&'~'));
+}
+
+#[test]
+#[should_panic]
+fn digit_radix_above_36_panics() {
+    RngComponent::with_seed(12345).digit(37);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random_string_ascii_alphabet() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let code = rng.random_string(1000, "ABC123");
+
+    assert_eq!(code.chars().count(), 1000);
+    assert!(code.chars().all(|c| "ABC123".contains(c)));
+    assert!("ABC123".chars().all(|c| code.contains(c)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random_string_multi_byte_alphabet() {
+    let mut rng = RngComponent::with_seed(12345);
+    let alphabet = "🎲🃏é漢";
+
+    let text = rng.random_string(500, alphabet);
+
+    assert_eq!(text.chars().count(), 500);
+    assert!(text.chars().all(|c| alphabet.contains(c)));
+    assert!(alphabet.chars().all(|c| text.contains(c)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random_string_edge_cases() {
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+
+    assert_eq!(rng_1.random_string(10, ""), "");
+    assert_eq!(rng_1.random_string(0, "abc"), "");
+    assert_eq!(rng_1.random_string(5, "x"), "xxxxx");
+
+    // Edge cases don't draw from the RNG
+    assert_eq!(rng_1.u64(..), rng_2.u64(..));
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "bevy_color")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_random_colors() {
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+
+    let first: Vec<Color> = (0..5).map(|_| rng_1.random_color()).collect();
+    let second: Vec<Color> = (0..5).map(|_| rng_2.random_color()).collect();
+
+    assert_eq!(first, second);
+
+    for color in first {
+        assert_eq!(color.a(), 1.0);
+        assert!((0.0..1.0).contains(&color.r()));
+        assert!((0.0..1.0).contains(&color.g()));
+        assert!((0.0..1.0).contains(&color.b()));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random_hsl_colors_only_vary_hue() {
+    let mut rng = GlobalRng::with_seed(23456);
+
+    for _ in 0..10 {
+        match rng.random_color_hsl(0.6, 0.4) {
+            Color::Hsla {
+                hue,
+                saturation,
+                lightness,
+                alpha,
+            } => {
+                assert!((0.0..360.0).contains(&hue));
+                assert_eq!(saturation, 0.6);
+                assert_eq!(lightness, 0.4);
+                assert_eq!(alpha, 1.0);
+            }
+            color => panic!("Expected a HSL color, received {:?}", color),
+        }
+    }
+}
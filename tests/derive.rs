@@ -0,0 +1,84 @@
+#![cfg(feature = "derive")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, RandomVariant)]
+enum Element {
+    Fire,
+    Water,
+    Earth,
+    #[allow(dead_code)]
+    Custom(u8),
+    Air,
+}
+
+#[derive(Debug, PartialEq, Eq, RandomVariant)]
+enum Slot<T> {
+    Empty,
+    Locked,
+    #[allow(dead_code)]
+    Filled(T),
+}
+
+#[derive(Debug, PartialEq, Eq, RandomVariant)]
+enum Single {
+    Only,
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn every_unit_variant_is_selected() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let mut counts = [0_u32; 4];
+
+    for _ in 0..4000 {
+        match Element::random(&mut rng) {
+            Element::Fire => counts[0] += 1,
+            Element::Water => counts[1] += 1,
+            Element::Earth => counts[2] += 1,
+            Element::Air => counts[3] += 1,
+            Element::Custom(_) => panic!("variants with fields should never be selected"),
+        }
+    }
+
+    for count in counts {
+        assert!(
+            (850..1150).contains(&count),
+            "variants should be selected uniformly, got {:?}",
+            counts
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn variant_selection_is_deterministic() {
+    let mut a = RngComponent::with_seed(42);
+    let mut b = RngComponent::with_seed(42);
+
+    let first: Vec<Element> = (0..32).map(|_| Element::random(&mut a)).collect();
+    let second: Vec<Element> = (0..32).map(|_| Element::random(&mut b)).collect();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_and_single_variant_enums() {
+    let mut rng = RngComponent::with_seed(7);
+
+    for _ in 0..100 {
+        assert!(matches!(
+            Slot::<String>::random(&mut rng),
+            Slot::Empty | Slot::Locked
+        ));
+        assert_eq!(Single::random(&mut rng), Single::Only);
+    }
+}
@@ -255,6 +255,75 @@ fn deterministic_secure_setup() {
     assert_eq!(enemy_2.u32(..=10), 9);
 }
 
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn clone_forks_and_clone_exact_copies() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut exact = rng.clone_exact();
+
+    assert_eq!(rng.u64(..), exact.u64(..));
+
+    let mut forked = rng.clone();
+    let mut expected = RngComponent::from(&mut exact);
+
+    // Cloning draws once from the parent, just like creating a component from it
+    assert_eq!(rng.u64(..), exact.u64(..));
+    assert_eq!(forked.u64(..), expected.u64(..));
+
+    let parent: Vec<u64> = (0..5).map(|_| rng.u64(..)).collect();
+    let child: Vec<u64> = (0..5).map(|_| forked.u64(..)).collect();
+
+    assert_ne!(parent, child);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn batch_from_seed_is_distinct_and_reproducible() {
+    let first: Vec<u64> = RngComponent::batch_from_seed(12345, 10_000)
+        .iter_mut()
+        .map(|rng| rng.u64(..))
+        .collect();
+    let second: Vec<u64> = RngComponent::batch_from_seed(12345, 10_000)
+        .iter_mut()
+        .map(|rng| rng.u64(..))
+        .collect();
+
+    assert_eq!(first.len(), 10_000);
+    assert_eq!(first, second);
+
+    let distinct: std::collections::HashSet<u64> = first.iter().copied().collect();
+
+    assert_eq!(distinct.len(), first.len());
+
+    let other: Vec<u64> = RngComponent::batch_from_seed(54321, 16)
+        .iter_mut()
+        .map(|rng| rng.u64(..))
+        .collect();
+
+    assert_ne!(first[..16], other[..]);
+    assert!(RngComponent::batch_from_seed(12345, 0).is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seed_bytes_match_seed() {
+    let seed: u64 = 0x0123_4567_89ab_cdef;
+
+    let mut global_1 = GlobalRng::with_seed_bytes(seed.to_le_bytes());
+    let mut global_2 = GlobalRng::with_seed(seed);
+
+    assert_eq!(global_1.u64(..), global_2.u64(..));
+
+    let mut component_1 = RngComponent::with_seed_bytes(seed.to_le_bytes());
+    let mut component_2 = RngComponent::with_seed(seed);
+
+    assert_eq!(component_1.u64(..), component_2.u64(..));
+    assert_ne!(
+        RngComponent::with_seed_bytes(seed.to_be_bytes()).u64(..),
+        RngComponent::with_seed(seed).u64(..)
+    );
+}
+
 #[cfg(feature = "serialize")]
 #[test]
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
@@ -265,3 +334,872 @@ fn load_rng_setup() {
 
     assert_eq!(rng.u32(..10), 4);
 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseed_repeats_sequence() {
+    let mut app = App::new();
+
+    let entity = app.world.spawn(RngComponent::with_seed(34567)).id();
+
+    let mut rng = app.world.get_mut::<RngComponent>(entity).unwrap();
+
+    rng.reseed(1234);
+
+    let first: Vec<u32> = (0..5).map(|_| rng.u32(..)).collect();
+
+    rng.reseed(1234);
+
+    let second: Vec<u32> = (0..5).map(|_| rng.u32(..)).collect();
+
+    assert_eq!(first, second);
+
+    let mut global = GlobalRng::with_seed(34567);
+
+    global.reseed(1234);
+
+    let global_first: Vec<u32> = (0..5).map(|_| global.u32(..)).collect();
+
+    global.reseed(1234);
+
+    let global_second: Vec<u32> = (0..5).map(|_| global.u32(..)).collect();
+
+    assert_eq!(global_first, global_second);
+    assert_eq!(first, global_first);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reseed_matches_with_seed() {
+    let mut rng = RngComponent::with_seed(34567);
+    let mut global = GlobalRng::with_seed(34567);
+    let mut generic = GlobalRng::with_seed(34567);
+
+    rng.u64(..);
+    global.u64(..);
+    generic.u64(..);
+
+    rng.reseed(42);
+    global.reseed(42);
+    reseed_generic(&mut generic, 42);
+
+    let mut expected = RngComponent::with_seed(42);
+
+    assert_eq!(rng, expected);
+
+    for _ in 0..10 {
+        let value = expected.u64(..);
+
+        assert_eq!(rng.u64(..), value);
+        assert_eq!(global.u64(..), value);
+        assert_eq!(generic.u64(..), value);
+    }
+}
+
+fn reseed_generic(rng: &mut impl DelegatedRng<Source = Rng>, seed: u64) {
+    rng.reseed(seed);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn entity_stable_seeding() {
+    let mut app = App::new();
+
+    let _ = app.world.spawn_empty().id();
+    let entity = app.world.spawn_empty().id();
+
+    let mut rng_1 = RngComponent::from_entity(entity, 12345);
+    let mut rng_2 = RngComponent::from_entity(Entity::from_bits(entity.to_bits()), 12345);
+
+    let first: Vec<u64> = (0..5).map(|_| rng_1.u64(..)).collect();
+    let second: Vec<u64> = (0..5).map(|_| rng_2.u64(..)).collect();
+
+    assert_eq!(first, second);
+
+    // Same index, but a different generation must not collide
+    let mut rng_3 =
+        RngComponent::from_entity(Entity::from_bits(entity.to_bits() + (1 << 32)), 12345);
+
+    assert_ne!(first[0], rng_3.u64(..));
+
+    // A different base seed must also change the sequence
+    let mut rng_4 = RngComponent::from_entity(entity, 54321);
+
+    assert_ne!(first[0], rng_4.u64(..));
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Resource)]
+struct Rolls(Vec<u32>);
+
+#[cfg(feature = "std")]
+fn roll_with_local_rng(mut rng: LocalRng, mut rolls: ResMut<Rolls>) {
+    let value = rng.u32(..=100);
+
+    rolls.0.push(value);
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_local_rng() {
+    let mut app = App::new();
+
+    app.insert_resource(GlobalRng::with_seed(45678))
+        .init_resource::<Rolls>()
+        .add_system(roll_with_local_rng);
+
+    app.update();
+    app.update();
+    app.update();
+
+    // The local RNG is seeded from the global once, then persists across runs
+    let mut global = GlobalRng::with_seed(45678);
+    let mut expected_rng = RngComponent::from(&mut global);
+    let expected: Vec<u32> = (0..3).map(|_| expected_rng.u32(..=100)).collect();
+
+    assert_eq!(app.world.resource::<Rolls>().0, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_global_forks() {
+    let mut global = GlobalRng::with_seed(56789);
+
+    let fork_1 = global.fork();
+    let fork_2 = global.fork();
+
+    let first: Vec<u64> = (0..5).map(|_| fork_1.u64(..)).collect();
+    let second: Vec<u64> = (0..5).map(|_| fork_2.u64(..)).collect();
+
+    assert_ne!(first, second);
+
+    let mut global = GlobalRng::with_seed(56789);
+
+    let replayed_1: Vec<u64> = {
+        let fork = global.fork();
+        (0..5).map(|_| fork.u64(..)).collect()
+    };
+    let replayed_2: Vec<u64> = {
+        let fork = global.fork();
+        (0..5).map(|_| fork.u64(..)).collect()
+    };
+
+    assert_eq!(first, replayed_1);
+    assert_eq!(second, replayed_2);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_secure_global_forks() {
+    let mut global = GlobalChaChaRng::with_seed([2; 40]);
+
+    let fork_1 = global.fork();
+    let fork_2 = global.fork();
+
+    assert_ne!(fork_1, fork_2);
+
+    let mut global = GlobalChaChaRng::with_seed([2; 40]);
+
+    assert_eq!(fork_1, global.fork());
+    assert_eq!(fork_2, global.fork());
+}
+
+fn spawn_enemies_with_rng(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+    for _ in 0..2 {
+        commands
+            .spawn_with_rng(&mut global, Enemy)
+            .insert(HitPoints { total: 10, max: 10 });
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_spawn_with_rng() {
+    let mut app = App::new();
+
+    app.insert_resource(GlobalRng::with_seed(23456));
+
+    app.add_startup_system(spawn_enemies_with_rng);
+
+    app.update();
+
+    let mut q_enemies = app
+        .world
+        .query_filtered::<&mut RngComponent, (With<Enemy>, With<HitPoints>)>();
+
+    let mut values: Vec<u32> = q_enemies
+        .iter_mut(&mut app.world)
+        .map(|mut rng| rng.u32(..=10))
+        .collect();
+
+    let mut global = GlobalRng::with_seed(23456);
+    let mut expected: Vec<u32> = (0..2)
+        .map(|_| RngComponent::from(&mut global).u32(..=10))
+        .collect();
+
+    values.sort_unstable();
+    expected.sort_unstable();
+
+    assert_eq!(values, expected);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn skip_matches_discarded_draws() {
+    let mut skipped = RngComponent::with_seed(67890);
+    let mut discarded = RngComponent::with_seed(67890);
+
+    skipped.skip(5);
+
+    for _ in 0..5 {
+        discarded.u64(..);
+    }
+
+    assert_eq!(skipped.u64(..), discarded.u64(..));
+
+    let mut global_skipped = GlobalRng::with_seed(67890);
+    let mut global_discarded = GlobalRng::with_seed(67890);
+
+    global_skipped.skip(1_000);
+    global_skipped.skip(0);
+
+    for _ in 0..1_000 {
+        global_discarded.u32(..);
+    }
+
+    assert_eq!(global_skipped.u32(..), global_discarded.u32(..));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn advance_to_restores_logged_draw_count() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(rng.draw_count(12345), 0);
+
+    // A mix of methods that consume varying numbers of draws
+    rng.u64(..);
+    rng.u128(..);
+    rng.f32();
+    rng.shuffle_portable(&mut [1, 2, 3, 4, 5]);
+    rng.get_mut().gen_u64();
+
+    let logged = rng.draw_count(12345);
+
+    assert!(logged >= 8, "{} draws logged", logged);
+
+    let mut restored = RngComponent::with_seed(99999);
+
+    restored.u64(..);
+    restored.advance_to(12345, logged);
+
+    assert_eq!(restored.draw_count(12345), logged);
+    assert_eq!(restored.u64(..), rng.u64(..));
+    assert_eq!(rng.draw_count(12345), logged + 1);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn peek_does_not_advance_state() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut untouched = RngComponent::with_seed(12345);
+
+    let peeked = rng.peek_u64();
+
+    assert_eq!(peeked, rng.peek_u64());
+    assert_eq!(peeked, rng.u64(..));
+    assert_eq!(peeked, untouched.u64(..));
+    assert_eq!(rng.u64(..), untouched.u64(..));
+
+    let mut global = GlobalRng::with_seed(12345);
+
+    let preview = global.peek(|rng| (rng.u8(..), rng.u8(..)));
+
+    assert_eq!(preview, (global.u8(..), global.u8(..)));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn peek_secure_does_not_advance_state() {
+    let mut rng = ChaChaRngComponent::with_seed([1; 40]);
+
+    let peeked = rng.peek_u64();
+
+    assert_eq!(peeked, rng.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_rng_continues_sequence() {
+    let rng = Rng::with_seed(12345);
+    let reference = Rng::with_seed(12345);
+
+    // Advance the source first, so it is no longer at its seeded state
+    rng.u64(..);
+    reference.u64(..);
+
+    let mut component = RngComponent::from_rng(rng.clone());
+    let mut global = GlobalRng::from_rng(rng);
+
+    let expected: Vec<u64> = (0..5).map(|_| reference.u64(..)).collect();
+
+    assert_eq!(
+        (0..5).map(|_| component.u64(..)).collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!((0..5).map(|_| global.u64(..)).collect::<Vec<_>>(), expected);
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn secure_from_rng_continues_sequence() {
+    let rng = ChaChaRng::with_seed([1; 40]);
+    let reference = ChaChaRng::with_seed([1; 40]);
+
+    rng.u64(..);
+    reference.u64(..);
+
+    let mut component = ChaChaRngComponent::from_rng(rng);
+
+    assert_eq!(component.u64(..), reference.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_fill_bytes() {
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+
+    // Covers lengths that are not a multiple of 8
+    for len in 0..=17 {
+        let mut buffer_1 = vec![0_u8; len];
+        let mut buffer_2 = vec![0_u8; len];
+
+        rng_1.fill_bytes(&mut buffer_1);
+        rng_2.fill_bytes(&mut buffer_2);
+
+        assert_eq!(buffer_1, buffer_2, "length {}", len);
+    }
+
+    let mut short = [0_u8; 13];
+    let mut long = [0_u8; 16];
+
+    RngComponent::with_seed(23456).fill_bytes(&mut short);
+    RngComponent::with_seed(23456).fill_bytes(&mut long);
+
+    assert_eq!(short, long[..13]);
+    assert_ne!(short, [0; 13]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill_bytes_empty_is_no_op() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut untouched = RngComponent::with_seed(12345);
+
+    rng.fill_bytes(&mut []);
+
+    assert_eq!(rng.u64(..), untouched.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random_iter_matches_direct_draws() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut reference = RngComponent::with_seed(12345);
+
+    let floats: Vec<f32> = rng.iter_f32().take(5).collect();
+    let expected: Vec<f32> = (0..5).map(|_| reference.f32()).collect();
+
+    assert_eq!(floats, expected);
+
+    let values: Vec<u64> = rng.iter_u64().take(5).collect();
+    let expected: Vec<u64> = (0..5).map(|_| reference.u64(..)).collect();
+
+    assert_eq!(values, expected);
+
+    let rolls: Vec<u8> = rng.iter_with(|rng| rng.u8(1..=6)).take(5).collect();
+    let expected: Vec<u8> = (0..5).map(|_| reference.u8(1..=6)).collect();
+
+    assert_eq!(rolls, expected);
+
+    // Values left untaken are never drawn
+    assert_eq!(rng.u64(..), reference.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn random_choices_are_deterministic() {
+    let notes = ["C", "D", "E", "G", "A"];
+
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+    let mut reference = RngComponent::with_seed(12345);
+
+    let first: Vec<&str> = rng_1.choices(&notes).take(16).copied().collect();
+    let second: Vec<&str> = rng_2.choices(&notes).take(16).copied().collect();
+    let expected: Vec<&str> = (0..16)
+        .map(|_| *reference.sample_portable(&notes).unwrap())
+        .collect();
+
+    assert_eq!(first, second);
+    assert_eq!(first, expected);
+
+    // Items outlive the iterator, which only borrows the RNG while it lives
+    let picked = {
+        let mut choices = rng_1.choices(&notes);
+
+        choices.next().unwrap()
+    };
+
+    assert!(notes.contains(picked));
+
+    let empty: [u8; 0] = [];
+
+    assert_eq!(rng_1.choices(&empty).next(), None);
+    assert_eq!(rng_1.choices(&empty).size_hint(), (0, Some(0)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn equality_follows_internal_state() {
+    let mut rng = RngComponent::with_seed(12345);
+    let exact = rng.clone_exact();
+
+    assert_eq!(rng, exact);
+    assert_eq!(GlobalRng::with_seed(12345), GlobalRng::with_seed(12345));
+
+    rng.u64(..);
+
+    assert_ne!(rng, exact);
+    assert_ne!(GlobalRng::with_seed(12345), GlobalRng::with_seed(54321));
+}
+
+#[cfg(all(feature = "serialize", feature = "std"))]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn hash_follows_internal_state() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut rng = RngComponent::with_seed(12345);
+    let exact = rng.clone_exact();
+
+    assert_eq!(hash_of(&rng), hash_of(&exact));
+
+    rng.u64(..);
+
+    assert_ne!(hash_of(&rng), hash_of(&exact));
+    assert_eq!(
+        hash_of(&GlobalRng::with_seed(12345)),
+        hash_of(&GlobalRng::with_seed(12345))
+    );
+    assert_ne!(
+        hash_of(&GlobalRng::with_seed(12345)),
+        hash_of(&GlobalRng::with_seed(54321))
+    );
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn state_hex_round_trip() {
+    let mut component = RngComponent::with_seed(12345);
+    let mut global = GlobalRng::with_seed(12345);
+
+    component.u64(..);
+
+    let hex = component.state_hex();
+
+    assert_eq!(hex.len(), 16);
+    assert!(hex.bytes().all(|byte| byte.is_ascii_hexdigit()));
+
+    let mut restored = RngComponent::from_state_hex(&hex).unwrap();
+    let mut restored_upper = RngComponent::from_state_hex(&hex.to_uppercase()).unwrap();
+
+    let expected = component.u64(..);
+
+    assert_eq!(restored.u64(..), expected);
+    assert_eq!(restored_upper.u64(..), expected);
+
+    let mut restored_global = GlobalRng::from_state_hex(&global.state_hex()).unwrap();
+
+    assert_eq!(restored_global.u64(..), global.u64(..));
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn raw_state_round_trip() {
+    let mut component = RngComponent::with_seed(12345);
+    let mut global = GlobalRng::with_seed(12345);
+
+    component.u64(..);
+    global.u64(..);
+
+    let mut restored = RngComponent::from_state(component.state());
+    let mut restored_global = GlobalRng::from_state(global.state());
+
+    for _ in 0..10 {
+        assert_eq!(restored.u64(..), component.u64(..));
+        assert_eq!(restored_global.u64(..), global.u64(..));
+    }
+
+    // The raw state is the same value as encoded in the hex string
+    assert_eq!(format!("{:016x}", component.state()), component.state_hex());
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn state_hex_rejects_malformed_input() {
+    assert_eq!(
+        RngComponent::from_state_hex("").unwrap_err(),
+        StateParseError::InvalidLength(0)
+    );
+    assert_eq!(
+        GlobalRng::from_state_hex("0123456789abcdef0").unwrap_err(),
+        StateParseError::InvalidLength(17)
+    );
+    assert_eq!(
+        RngComponent::from_state_hex("0123456789abcdeg").unwrap_err(),
+        StateParseError::InvalidDigit
+    );
+    assert_eq!(
+        GlobalRng::from_state_hex("+123456789abcdef").unwrap_err(),
+        StateParseError::InvalidDigit
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn secure_random_bytes() {
+    let mut component = ChaChaRngComponent::with_seed([7; 40]);
+    let mut global = GlobalChaChaRng::with_seed([7; 40]);
+    let mut other = ChaChaRngComponent::with_seed([8; 40]);
+
+    let short: [u8; 5] = component.random_bytes();
+    let token: [u8; 64] = component.random_bytes();
+
+    assert_eq!(short.len(), 5);
+    assert_eq!(token.len(), 64);
+
+    assert_eq!(global.random_bytes::<5>(), short);
+    assert_eq!(global.random_bytes::<64>(), token);
+    assert_ne!(other.random_bytes::<64>(), token);
+
+    assert_eq!(component.random_bytes::<0>(), [0_u8; 0]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn split_children_are_distinct_and_reproducible() {
+    let mut parent = RngComponent::with_seed(12345);
+    let mut twin = parent.clone_exact();
+    let mut reference = parent.clone_exact();
+
+    let mut children = parent.split(8);
+    let mut twin_children = twin.split(8);
+
+    assert_eq!(children.len(), 8);
+
+    let values: Vec<u64> = children.iter_mut().map(|child| child.u64(..)).collect();
+    let twin_values: Vec<u64> = twin_children
+        .iter_mut()
+        .map(|child| child.u64(..))
+        .collect();
+
+    assert_eq!(values, twin_values);
+
+    let mut distinct = values.clone();
+
+    distinct.sort_unstable();
+    distinct.dedup();
+
+    assert_eq!(distinct.len(), values.len());
+
+    // The parent is advanced exactly once
+    reference.u64(..);
+
+    assert_eq!(parent.u64(..), reference.u64(..));
+    assert!(parent.split(0).is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn seeded_hashers_hash_keys_identically() {
+    use std::collections::HashMap;
+    use std::hash::BuildHasher;
+
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+
+    let state_1 = rng_1.seeded_hasher();
+    let state_2 = rng_2.seeded_hasher();
+
+    assert_eq!(state_1, state_2);
+
+    for key in ["sword", "shield", "", "a much longer key than eight bytes"] {
+        assert_eq!(state_1.hash_one(key), state_2.hash_one(key));
+    }
+
+    let mut map_1 = HashMap::with_hasher(state_1);
+    let mut map_2 = HashMap::with_hasher(state_2);
+
+    for index in 0..100_u32 {
+        map_1.insert(index, index * 2);
+        map_2.insert(index, index * 2);
+    }
+
+    assert!(map_1.iter().eq(map_2.iter()));
+
+    // Different RNG states give different hashers
+    let other = rng_1.seeded_hasher();
+
+    assert_ne!(other, state_1);
+    assert_ne!(other.hash_one("sword"), state_1.hash_one("sword"));
+    assert_ne!(state_1.hash_one([0_u8]), state_1.hash_one([0_u8, 0]));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn secure_global_forks_deterministic_insecure_components() {
+    let mut global_1 = GlobalChaChaRng::with_seed([7; 40]);
+    let mut global_2 = GlobalChaChaRng::with_seed([7; 40]);
+
+    let mut fork_1 = global_1.fork_insecure();
+    let mut fork_2 = global_2.fork_insecure();
+    let first = fork_1.clone_exact();
+
+    assert_eq!(
+        (0..5).map(|_| fork_1.u64(..)).collect::<Vec<_>>(),
+        (0..5).map(|_| fork_2.u64(..)).collect::<Vec<_>>()
+    );
+
+    // Each fork advances the secure source, so subsequent forks differ
+    assert_ne!(global_1.fork_insecure(), first);
+
+    let _ = global_2.fork_insecure();
+
+    assert_eq!(global_1.u64(..), global_2.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn named_streams_are_stable_and_independent() {
+    let mut streams_1 = RngStreams::with_seed(12345);
+    let mut streams_2 = RngStreams::with_seed(12345);
+
+    // Creating streams in a different order doesn't change them
+    let loot_1 = streams_1.stream("loot").u64(..);
+    let weather_1 = streams_1.stream("weather").u64(..);
+    let weather_2 = streams_2.stream("weather").u64(..);
+    let loot_2 = streams_2.stream("loot").u64(..);
+
+    assert_eq!(loot_1, loot_2);
+    assert_eq!(weather_1, weather_2);
+    assert_ne!(loot_1, weather_1);
+    assert_eq!(streams_1.len(), 2);
+
+    // Drawing from one stream leaves the others untouched
+    for _ in 0..10 {
+        streams_1.stream("ai").u64(..);
+    }
+
+    assert_eq!(
+        streams_1.stream("loot").u64(..),
+        streams_2.stream("loot").u64(..)
+    );
+
+    let mut other_seed = RngStreams::with_seed(54321);
+
+    assert_ne!(other_seed.stream("loot").u64(..), loot_1);
+
+    // Stream seeds must never change, so that saved master seeds keep working
+    assert_eq!(
+        RngStreams::with_seed(12345).stream("loot").u64(..),
+        Rng::with_seed(0x23d7_f17e_fc80_f812).u64(..)
+    );
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weak_secure_seeds_are_rejected() {
+    assert_eq!(
+        GlobalChaChaRng::with_seed_checked([0; 40]).unwrap_err(),
+        WeakSeedError::AllZero
+    );
+    assert_eq!(
+        GlobalChaChaRng::with_seed_checked([0xab; 40]).unwrap_err(),
+        WeakSeedError::RepeatedByte(0xab)
+    );
+
+    let mut seed = [0; 40];
+
+    seed[39] = 1;
+
+    let mut checked = GlobalChaChaRng::with_seed_checked(seed).unwrap();
+    let mut unchecked = GlobalChaChaRng::with_seed(seed);
+
+    assert_eq!(checked.u64(..), unchecked.u64(..));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn global_seeded_from_secure_source() {
+    let source_1 = ChaChaRng::with_seed([7; 40]);
+    let source_2 = ChaChaRng::with_seed([7; 40]);
+
+    let mut global_1 = GlobalRng::from_source(&source_1);
+    let mut global_2 = GlobalRng::from_source(&source_2);
+
+    assert_eq!(global_1, global_2);
+    assert_eq!(global_1.u64(..), global_2.u64(..));
+
+    // Seeding takes a single draw from the source
+    let reference = ChaChaRng::with_seed([7; 40]);
+
+    assert_eq!(
+        GlobalRng::from_source(&reference),
+        GlobalRng::with_seed(ChaChaRng::with_seed([7; 40]).u64(..))
+    );
+    assert_eq!(source_1.u64(..), reference.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn string_seeds_are_stable() {
+    // Reference FNV-1a test vectors, which must never change
+    assert_eq!(
+        GlobalRng::with_string_seed(""),
+        GlobalRng::with_seed(0xcbf2_9ce4_8422_2325)
+    );
+    assert_eq!(
+        GlobalRng::with_string_seed("a"),
+        GlobalRng::with_seed(0xaf63_dc4c_8601_ec8c)
+    );
+    assert_eq!(
+        GlobalRng::with_string_seed("foobar"),
+        GlobalRng::with_seed(0x8594_4171_f739_67e8)
+    );
+
+    let mut world_1 = GlobalRng::with_string_seed("Glacier Peaks");
+    let mut world_2 = GlobalRng::with_string_seed("Glacier Peaks");
+    let mut world_3 = GlobalRng::with_string_seed("glacier peaks");
+
+    let expected = world_1.u64(..);
+
+    assert_eq!(world_2.u64(..), expected);
+    assert_ne!(world_3.u64(..), expected);
+}
+
+#[derive(Debug, Component)]
+struct Rolled {
+    seed: u64,
+    value: f64,
+}
+
+fn roll_per_entity(mut q_rolled: Query<(&mut Rolled, &mut RngComponent)>) {
+    q_rolled.for_each_with_rng(|mut rolled, value| {
+        rolled.value = value;
+    });
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn query_draws_one_value_per_entity() {
+    let mut app = App::new();
+
+    for seed in [1, 2, 3, 4, 5] {
+        app.world
+            .spawn((Rolled { seed, value: -1.0 }, RngComponent::with_seed(seed)));
+    }
+
+    app.add_system(roll_per_entity);
+
+    app.update();
+
+    let mut query = app.world.query::<&Rolled>();
+    let rolled: Vec<(u64, f64)> = query
+        .iter(&app.world)
+        .map(|rolled| (rolled.seed, rolled.value))
+        .collect();
+
+    assert_eq!(rolled.len(), 5);
+
+    // Every entity gets the first value of its own seeded sequence
+    for (seed, value) in rolled {
+        assert_eq!(value, RngComponent::with_seed(seed).f64());
+    }
+
+    // The next update draws the second value of each sequence
+    app.update();
+
+    for rolled in query.iter(&app.world) {
+        let mut expected = RngComponent::with_seed(rolled.seed);
+
+        expected.f64();
+
+        assert_eq!(rolled.value, expected.f64());
+    }
+}
+
+#[test]
+#[should_panic(expected = "GlobalRng already borrowed via get_cell")]
+fn nested_get_cell_panics() {
+    let global = GlobalRng::with_seed(12345);
+
+    let _cell = global.get_cell();
+    let _nested = global.get_cell();
+}
+
+#[test]
+#[should_panic(expected = "GlobalRng already borrowed via get_cell")]
+fn formatting_while_holding_cell_panics() {
+    let global = GlobalRng::with_seed(12345);
+
+    let _cell = global.get_cell();
+
+    let _ = format!("{:?}", global);
+}
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+#[test]
+fn get_cell_waits_on_other_threads() {
+    let global = GlobalRng::with_seed(12345);
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..1_000 {
+                    global.get_cell().u64(..);
+                }
+            });
+        }
+    });
+
+    let mut expected = GlobalRng::with_seed(12345);
+
+    for _ in 0..4_000 {
+        expected.u64(..);
+    }
+
+    // All handles were released, so comparing can take them again
+    assert_eq!(global, expected);
+}
@@ -0,0 +1,108 @@
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_single_die() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    for _ in 0..1000 {
+        let value = rng.roll("1d20").unwrap();
+
+        assert!((1..=20).contains(&value), "{} out of range", value);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_dice_with_modifier() {
+    let mut rng = GlobalRng::with_seed(12345);
+
+    let rolls: Vec<i64> = (0..1000).map(|_| rng.roll("2d6+3").unwrap()).collect();
+
+    assert!(rolls.iter().all(|value| (5..=15).contains(value)));
+    assert!(rolls.contains(&5) && rolls.contains(&15));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_multiple_terms() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let rolls: Vec<i64> = (0..5000).map(|_| rng.roll("4d8-1+1d4").unwrap()).collect();
+
+    assert!(rolls.iter().all(|value| (4..=35).contains(value)));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_is_deterministic() {
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+
+    assert_eq!(rng_1.roll("4d8-1+1d4"), rng_2.roll(" 4D8 - 1 + 1d4 "));
+    assert_eq!(rng_1.roll("d20"), rng_2.roll("1d20"));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_flat_and_negative_terms() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(rng.roll("7"), Ok(7));
+    assert_eq!(rng.roll("-3+1"), Ok(-2));
+
+    let value = rng.roll("-1d6").unwrap();
+
+    assert!((-6..=-1).contains(&value));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_invalid_notation() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(rng.roll(""), Err(DiceParseError::Empty));
+    assert_eq!(rng.roll("1d6+"), Err(DiceParseError::Empty));
+    assert_eq!(rng.roll("d"), Err(DiceParseError::InvalidSides));
+    assert_eq!(rng.roll("2d0"), Err(DiceParseError::InvalidSides));
+    assert_eq!(rng.roll("3x6"), Err(DiceParseError::InvalidModifier));
+    assert_eq!(rng.roll("xd6"), Err(DiceParseError::InvalidCount));
+    assert_eq!(rng.roll("1d6d6"), Err(DiceParseError::InvalidSides));
+    assert_eq!(
+        rng.roll("9223372036854775807+1d6"),
+        Err(DiceParseError::Overflow)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_limits_dice_count() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(rng.roll("0d6"), Err(DiceParseError::InvalidCount));
+    assert_eq!(rng.roll("2d6+0d4"), Err(DiceParseError::InvalidCount));
+    assert_eq!(rng.roll("1001d6"), Err(DiceParseError::InvalidCount));
+    assert_eq!(rng.roll("4000000000d6"), Err(DiceParseError::InvalidCount));
+    assert_eq!(rng.roll("4294967295d6"), Err(DiceParseError::InvalidCount));
+
+    // Up to 1000 dice per term are allowed, in any number of terms
+    assert!((1_000..=6_000).contains(&rng.roll("1000d6").unwrap()));
+    assert!((2_000..=12_000).contains(&rng.roll("1000d6+1000d6").unwrap()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn roll_invalid_notation_leaves_state_untouched() {
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+
+    assert!(rng_1.roll("2d6+x").is_err());
+
+    assert_eq!(rng_1.u64(..), rng_2.u64(..));
+}
@@ -0,0 +1,555 @@
+#![cfg(feature = "std")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const SAMPLES: usize = 100_000;
+
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+
+    (mean, variance)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn normal_distribution_statistics() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let values: Vec<f64> = (0..SAMPLES).map(|_| rng.f64_normal(10.0, 2.0)).collect();
+
+    let (mean, variance) = mean_and_variance(&values);
+
+    assert!((mean - 10.0).abs() < 0.05, "mean was {}", mean);
+    assert!((variance - 4.0).abs() < 0.1, "variance was {}", variance);
+
+    let mut rng = GlobalRng::with_seed(12345);
+
+    let values: Vec<f64> = (0..SAMPLES)
+        .map(|_| f64::from(rng.f32_normal(-3.0, 0.5)))
+        .collect();
+
+    let (mean, variance) = mean_and_variance(&values);
+
+    assert!((mean + 3.0).abs() < 0.05, "mean was {}", mean);
+    assert!((variance - 0.25).abs() < 0.01, "variance was {}", variance);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_normal_distribution() {
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let first: Vec<f64> = (0..10).map(|_| rng_1.f64_normal(0.0, 1.0)).collect();
+    let second: Vec<f64> = (0..10).map(|_| rng_2.f64_normal(0.0, 1.0)).collect();
+
+    assert_eq!(first, second);
+    assert_eq!(rng_1.f64_normal(5.0, 0.0), 5.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn normal_pair_statistics() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let pairs: Vec<(f64, f64)> = (0..SAMPLES)
+        .map(|_| rng.f64_normal_pair(10.0, 2.0))
+        .collect();
+
+    let xs: Vec<f64> = pairs.iter().map(|&(x, _)| x).collect();
+    let ys: Vec<f64> = pairs.iter().map(|&(_, y)| y).collect();
+
+    for values in [&xs, &ys] {
+        let (mean, variance) = mean_and_variance(values);
+
+        assert!((mean - 10.0).abs() < 0.05, "mean was {}", mean);
+        assert!((variance - 4.0).abs() < 0.1, "variance was {}", variance);
+    }
+
+    // The two halves of each pair should be uncorrelated
+    let covariance = pairs
+        .iter()
+        .map(|&(x, y)| (x - 10.0) * (y - 10.0))
+        .sum::<f64>()
+        / SAMPLES as f64;
+    let correlation = covariance / 4.0;
+
+    assert!(correlation.abs() < 0.02, "correlation was {}", correlation);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_normal_pair() {
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let first: Vec<(f64, f64)> = (0..10).map(|_| rng_1.f64_normal_pair(0.0, 1.0)).collect();
+    let second: Vec<(f64, f64)> = (0..10).map(|_| rng_2.f64_normal_pair(0.0, 1.0)).collect();
+
+    assert_eq!(first, second);
+
+    // The first value of the pair matches the single value, consuming the same draws
+    let (x, _) = rng_1.f64_normal_pair(0.0, 1.0);
+    let single = rng_2.f64_normal(0.0, 1.0);
+
+    assert!((x - single).abs() < 1e-12, "{} != {}", x, single);
+    assert_eq!(rng_1, rng_2);
+    assert_eq!(rng_1.f64_normal_pair(5.0, 0.0), (5.0, 5.0));
+}
+
+#[test]
+#[should_panic(expected = "std_dev must be a finite, non-negative value")]
+fn normal_pair_negative_std_dev_panics() {
+    let mut rng = RngComponent::with_seed(1);
+
+    let _ = rng.f64_normal_pair(0.0, -1.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn one_in_probabilities() {
+    const TRIALS: usize = 10_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert!((0..TRIALS).all(|_| rng.one_in(1)));
+    assert!((0..TRIALS).all(|_| !rng.one_in(u64::MAX)));
+
+    let hits = (0..TRIALS).filter(|_| rng.one_in(4)).count();
+
+    assert!(
+        (2_200..=2_800).contains(&hits),
+        "{} hits out of range",
+        hits
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chance_in_probabilities() {
+    const TRIALS: usize = 10_000;
+
+    let mut rng = GlobalRng::with_seed(12345);
+
+    assert!((0..TRIALS).all(|_| !rng.chance_in(0, 7)));
+    assert!((0..TRIALS).all(|_| rng.chance_in(7, 7)));
+    assert!((0..TRIALS).all(|_| rng.chance_in(8, 7)));
+
+    let hits = (0..TRIALS).filter(|_| rng.chance_in(3, 8)).count();
+
+    assert!(
+        (3_450..=4_050).contains(&hits),
+        "{} hits out of range",
+        hits
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn bool_ratio_probabilities() {
+    const TRIALS: usize = 10_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert!((0..TRIALS).all(|_| rng.bool_ratio(1, 0)));
+    assert!((0..TRIALS).all(|_| !rng.bool_ratio(0, 1)));
+    assert!((0..TRIALS).all(|_| rng.bool_ratio(u32::MAX, 0)));
+
+    let hits = (0..TRIALS).filter(|_| rng.bool_ratio(3, 1)).count();
+
+    assert!(
+        (7_200..=7_800).contains(&hits),
+        "{} hits out of range",
+        hits
+    );
+
+    // Large weights don't overflow when summed
+    let hits = (0..TRIALS)
+        .filter(|_| rng.bool_ratio(u32::MAX, u32::MAX))
+        .count();
+
+    assert!(
+        (4_700..=5_300).contains(&hits),
+        "{} hits out of range",
+        hits
+    );
+}
+
+#[test]
+#[should_panic]
+fn bool_ratio_zero_weights_panics() {
+    RngComponent::with_seed(12345).bool_ratio(0, 0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn chance_percent_probabilities() {
+    const TRIALS: usize = 100_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert!((0..TRIALS).all(|_| !rng.chance_percent(0)));
+    assert!((0..TRIALS).all(|_| rng.chance_percent(100)));
+    assert!((0..TRIALS).all(|_| rng.chance_percent(u8::MAX)));
+
+    let hits = (0..TRIALS).filter(|_| rng.chance_percent(1)).count();
+
+    assert!((800..=1_200).contains(&hits), "{} hits out of range", hits);
+
+    let hits = (0..TRIALS).filter(|_| rng.chance_percent(99)).count();
+
+    assert!(
+        (98_800..=99_200).contains(&hits),
+        "{} hits out of range",
+        hits
+    );
+
+    let hits = (0..TRIALS).filter(|_| rng.chance_percent(15)).count();
+
+    assert!(
+        (14_500..=15_500).contains(&hits),
+        "{} hits out of range",
+        hits
+    );
+
+    // Matches the equivalent integer ratio draw exactly
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    for percent in 0..=100 {
+        assert_eq!(
+            rng_1.chance_percent(percent),
+            rng_2.chance_in(u64::from(percent), 100)
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn one_in_zero_panics() {
+    RngComponent::with_seed(12345).one_in(0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn poisson_mean_and_variance() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    // Covers Knuth's method, the threshold, and transformed rejection
+    for lambda in [0.5, 4.0, 10.0, 42.0, 1_000.0] {
+        let values: Vec<f64> = (0..SAMPLES).map(|_| rng.poisson(lambda) as f64).collect();
+        let (mean, variance) = mean_and_variance(&values);
+
+        assert!(
+            (mean - lambda).abs() < lambda.sqrt() * 0.05,
+            "lambda {}: mean {}",
+            lambda,
+            mean
+        );
+        assert!(
+            (variance - lambda).abs() < lambda * 0.1,
+            "lambda {}: variance {}",
+            lambda,
+            variance
+        );
+    }
+
+    assert_eq!(rng.poisson(0.0), 0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn exponential_mean() {
+    let mut rng = GlobalRng::with_seed(12345);
+
+    for lambda in [0.5, 2.0, 25.0] {
+        let values: Vec<f64> = (0..SAMPLES).map(|_| rng.exponential(lambda)).collect();
+        let (mean, _) = mean_and_variance(&values);
+
+        assert!(values.iter().all(|&v| v >= 0.0 && v.is_finite()));
+        assert!(
+            (mean - 1.0 / lambda).abs() < 0.05 / lambda,
+            "lambda {}: mean {}",
+            lambda,
+            mean
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn exponential_zero_lambda_panics() {
+    RngComponent::with_seed(12345).exponential(0.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn triangular_stays_in_range_and_peaks_at_mode() {
+    const BUCKETS: usize = 20;
+
+    let mut rng = RngComponent::with_seed(12345);
+    let mut counts = [0_usize; BUCKETS];
+
+    for _ in 0..SAMPLES {
+        let value = rng.triangular(10.0, 30.0, 15.0);
+
+        assert!((10.0..=30.0).contains(&value), "{} out of range", value);
+
+        counts[(((value - 10.0) / 20.0) * BUCKETS as f64).min(BUCKETS as f64 - 1.0) as usize] += 1;
+    }
+
+    let peak = (0..BUCKETS).max_by_key(|&bucket| counts[bucket]).unwrap();
+
+    // The mode of 15.0 falls into the boundary between the 5th and 6th buckets
+    assert!((4..=5).contains(&peak), "peak at bucket {}", peak);
+
+    let values: Vec<f64> = (0..SAMPLES)
+        .map(|_| rng.triangular(0.0, 3.0, 3.0))
+        .collect();
+    let (mean, _) = mean_and_variance(&values);
+
+    assert!((mean - 2.0).abs() < 0.02, "mean {}", mean);
+    assert_eq!(rng.triangular(5.0, 5.0, 5.0), 5.0);
+}
+
+#[test]
+#[should_panic]
+fn triangular_invalid_mode_panics() {
+    RngComponent::with_seed(12345).triangular(0.0, 1.0, 2.0);
+}
+
+/// Pearson's chi-square statistic of the observed counts against a uniform distribution.
+fn chi_square(counts: &[usize]) -> f64 {
+    let total: usize = counts.iter().sum();
+    let expected = total as f64 / counts.len() as f64;
+
+    counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn integer_ranges_are_uniform() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    // Critical values at p = 0.001, for 6, 10 & 199 degrees of freedom respectively
+    let mut counts = [0_usize; 7];
+
+    for _ in 0..SAMPLES {
+        counts[rng.u64(0..7) as usize] += 1;
+    }
+
+    assert!(chi_square(&counts) < 22.46, "{:?}", counts);
+
+    let mut counts = [0_usize; 11];
+
+    for _ in 0..SAMPLES {
+        counts[(rng.i32(-5..=5) + 5) as usize] += 1;
+    }
+
+    assert!(chi_square(&counts) < 29.59, "{:?}", counts);
+
+    let mut counts = [0_usize; 200];
+
+    for _ in 0..SAMPLES {
+        counts[usize::from(rng.u8(..200))] += 1;
+    }
+
+    assert!(chi_square(&counts) < 267.5, "{:?}", counts);
+}
+
+/// A custom distribution of the sum of `count` `sides`-sided dice, composing another
+/// custom distribution for the individual dice.
+struct DiceSum {
+    count: u32,
+    die: Die,
+}
+
+struct Die {
+    sides: u32,
+}
+
+impl SampleFromRng<u32> for Die {
+    fn sample(&self, rng: &mut impl DelegatedRng) -> u32 {
+        rng.u32(1..=self.sides)
+    }
+}
+
+impl SampleFromRng<u32> for DiceSum {
+    fn sample(&self, rng: &mut impl DelegatedRng) -> u32 {
+        (0..self.count).map(|_| rng.draw(&self.die)).sum()
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn custom_distributions_through_draw() {
+    let dice = DiceSum {
+        count: 3,
+        die: Die { sides: 6 },
+    };
+
+    let mut rng = RngComponent::with_seed(12345);
+    let mut reference = RngComponent::with_seed(12345);
+
+    let values: Vec<f64> = (0..SAMPLES).map(|_| f64::from(rng.draw(&dice))).collect();
+
+    assert!(values.iter().all(|&value| (3.0..=18.0).contains(&value)));
+
+    let (mean, _) = mean_and_variance(&values);
+
+    assert!((mean - 10.5).abs() < 0.05, "mean {}", mean);
+
+    // Drawing is the same as summing the dice directly
+    let expected: u32 = (0..SAMPLES * 3).map(|_| reference.u32(1..=6)).sum();
+
+    assert_eq!(values.iter().sum::<f64>() as u32, expected);
+
+    let mut global = GlobalRng::with_seed(12345);
+
+    assert!((1..=6).contains(&global.draw(&Die { sides: 6 })));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn advantage_and_disadvantage_skew_rolls() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let mean_of = |values: Vec<u32>| {
+        mean_and_variance(&values.into_iter().map(f64::from).collect::<Vec<f64>>()).0
+    };
+
+    let advantage = mean_of((0..SAMPLES).map(|_| rng.u32_advantage(1..=20)).collect());
+    let disadvantage = mean_of((0..SAMPLES).map(|_| rng.u32_disadvantage(1..=20)).collect());
+    let best_of_four = mean_of((0..SAMPLES).map(|_| rng.best_of(4, 1..=20)).collect());
+
+    // Expected means are 13.825, 7.175 & roughly 16.48, against 10.5 for a single roll
+    assert!(
+        (advantage - 13.825).abs() < 0.1,
+        "advantage mean {}",
+        advantage
+    );
+    assert!(
+        (disadvantage - 7.175).abs() < 0.1,
+        "disadvantage mean {}",
+        disadvantage
+    );
+    assert!(
+        best_of_four > advantage,
+        "best of four mean {}",
+        best_of_four
+    );
+
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let first = rng_2.i64(-3..3);
+    let second = rng_2.i64(-3..3);
+
+    assert_eq!(rng_1.advantage(-3_i64..3), first.max(second));
+    assert_eq!(rng_1.best_of(1, 5_u8..=5), 5);
+}
+
+#[test]
+#[should_panic]
+fn best_of_zero_panics() {
+    RngComponent::with_seed(12345).best_of(0, 1_u32..=6);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gamma_mean_and_variance() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    // Covers the boosted small shape path as well as Marsaglia-Tsang directly
+    for (shape, scale) in [(0.5, 1.0), (1.0, 3.0), (2.0, 2.0), (9.0, 0.5)] {
+        let values: Vec<f64> = (0..SAMPLES).map(|_| rng.gamma(shape, scale)).collect();
+        let (mean, variance) = mean_and_variance(&values);
+        let expected_variance = shape * scale * scale;
+
+        assert!(values.iter().all(|&v| v >= 0.0 && v.is_finite()));
+        assert!(
+            (mean - shape * scale).abs() < 0.02 * shape * scale + 0.01,
+            "shape {}, scale {}: mean {}",
+            shape,
+            scale,
+            mean
+        );
+        assert!(
+            (variance - expected_variance).abs() < 0.1 * expected_variance,
+            "shape {}, scale {}: variance {}",
+            shape,
+            scale,
+            variance
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn beta_mean() {
+    let mut rng = GlobalRng::with_seed(12345);
+
+    for (alpha, beta) in [(2.0, 5.0), (0.5, 0.5), (1.0, 1.0), (8.0, 2.0)] {
+        let values: Vec<f64> = (0..SAMPLES).map(|_| rng.beta(alpha, beta)).collect();
+        let (mean, _) = mean_and_variance(&values);
+
+        assert!(values.iter().all(|v| (0.0..=1.0).contains(v)));
+        assert!(
+            (mean - alpha / (alpha + beta)).abs() < 0.01,
+            "alpha {}, beta {}: mean {}",
+            alpha,
+            beta,
+            mean
+        );
+    }
+
+    // Tiny parameters stay within bounds even when the gamma draws underflow
+    assert!((0..1_000).all(|_| (0.0..=1.0).contains(&rng.beta(1e-3, 1e-3))));
+}
+
+#[test]
+#[should_panic]
+fn gamma_zero_shape_panics() {
+    RngComponent::with_seed(12345).gamma(0.0, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn beta_negative_parameter_panics() {
+    RngComponent::with_seed(12345).beta(1.0, -1.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sign_is_evenly_split() {
+    const TRIALS: usize = 100_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    let signs: Vec<i32> = (0..TRIALS).map(|_| rng.sign()).collect();
+
+    assert!(signs.iter().all(|&sign| sign == -1 || sign == 1));
+
+    let positives = signs.iter().filter(|&&sign| sign == 1).count();
+
+    assert!(
+        (49_000..=51_000).contains(&positives),
+        "{} positives out of range",
+        positives
+    );
+
+    // Floats get exactly the same two values
+    assert!((0..1_000)
+        .map(|_| rng.sign::<f64>())
+        .all(|sign| sign == -1.0 || sign == 1.0));
+}
@@ -0,0 +1,215 @@
+#![cfg(feature = "bevy_math")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+const SAMPLES: usize = 20_000;
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unit_vectors_are_normalized() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    for _ in 0..1_000 {
+        assert!((rng.unit_vec2().length() - 1.0).abs() < 1e-5);
+        assert!((rng.unit_vec3().length() - 1.0).abs() < 1e-5);
+        assert!((rng.random_quat().length() - 1.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn unit_vectors_are_not_clustered() {
+    let mut rng = RngComponent::with_seed(23456);
+
+    let mean_2d = (0..SAMPLES).map(|_| rng.unit_vec2()).sum::<Vec2>() / SAMPLES as f32;
+
+    assert!(mean_2d.length() < 0.02, "2d mean was {:?}", mean_2d);
+
+    let mut octants = [0usize; 8];
+    let mut sum_3d = Vec3::ZERO;
+
+    for _ in 0..SAMPLES {
+        let direction = rng.unit_vec3();
+
+        sum_3d += direction;
+
+        let octant = usize::from(direction.x > 0.0)
+            | usize::from(direction.y > 0.0) << 1
+            | usize::from(direction.z > 0.0) << 2;
+
+        octants[octant] += 1;
+    }
+
+    assert!(
+        (sum_3d / SAMPLES as f32).length() < 0.02,
+        "3d mean was {:?}",
+        sum_3d / SAMPLES as f32
+    );
+
+    let expected = SAMPLES as f32 / 8.0;
+
+    for count in octants {
+        assert!(
+            (count as f32 - expected).abs() < expected * 0.1,
+            "octants were {:?}",
+            octants
+        );
+    }
+
+    // Rotating a fixed axis by uniform rotations should also yield uniform directions
+    let mean_rotated = (0..SAMPLES)
+        .map(|_| rng.random_quat() * Vec3::X)
+        .sum::<Vec3>()
+        / SAMPLES as f32;
+
+    assert!(
+        mean_rotated.length() < 0.02,
+        "rotated mean was {:?}",
+        mean_rotated
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn points_fall_inside_2d_shapes() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let (min, max) = (Vec2::new(-10.0, 2.0), Vec2::new(4.0, 3.0));
+    let center = Vec2::new(5.0, -5.0);
+
+    for _ in 0..1_000 {
+        let point = rng.random_in_rect(min, max);
+
+        assert!(
+            point.cmpge(min).all() && point.cmple(max).all(),
+            "{:?}",
+            point
+        );
+        assert!(rng.random_in_circle(center, 3.0).distance(center) <= 3.0 + 1e-5);
+        assert!((rng.random_on_circle(center, 3.0).distance(center) - 3.0).abs() < 1e-5);
+    }
+
+    assert_eq!(rng.random_in_rect(min, min), min);
+    assert_eq!(rng.random_in_circle(center, 0.0), center);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn points_cover_2d_areas_uniformly() {
+    let mut rng = RngComponent::with_seed(23456);
+
+    // Quadrants of the rectangle each cover a quarter of the area
+    let mut quadrants = [0usize; 4];
+
+    for _ in 0..SAMPLES {
+        let point = rng.random_in_rect(Vec2::ZERO, Vec2::new(2.0, 4.0));
+
+        quadrants[usize::from(point.x > 1.0) | usize::from(point.y > 2.0) << 1] += 1;
+    }
+
+    let expected = SAMPLES as f32 / 4.0;
+
+    for count in quadrants {
+        assert!(
+            (count as f32 - expected).abs() < expected * 0.1,
+            "quadrants were {:?}",
+            quadrants
+        );
+    }
+
+    // The inner circle of half the radius covers a quarter of the area, so should receive
+    // a quarter of the points rather than half of them
+    let inner = (0..SAMPLES)
+        .filter(|_| rng.random_in_circle(Vec2::ZERO, 2.0).length() < 1.0)
+        .count();
+
+    assert!(
+        (inner as f32 - expected).abs() < expected * 0.1,
+        "{} points within the inner circle",
+        inner
+    );
+
+    let mean = (0..SAMPLES)
+        .map(|_| rng.random_on_circle(Vec2::ONE, 1.0))
+        .sum::<Vec2>()
+        / SAMPLES as f32;
+
+    assert!((mean - Vec2::ONE).length() < 0.02, "mean was {:?}", mean);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn points_fall_inside_3d_shapes() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let (min, max) = (Vec3::new(-1.0, 0.0, 5.0), Vec3::new(1.0, 10.0, 6.0));
+    let center = Vec3::new(0.0, -3.0, 2.0);
+
+    for _ in 0..1_000 {
+        let point = rng.random_in_aabb(min, max);
+
+        assert!(
+            point.cmpge(min).all() && point.cmple(max).all(),
+            "{:?}",
+            point
+        );
+        assert!(rng.random_in_sphere(center, 4.0).distance(center) <= 4.0 + 1e-5);
+        assert!((rng.random_on_sphere(center, 4.0).distance(center) - 4.0).abs() < 1e-5);
+    }
+
+    assert_eq!(rng.random_in_aabb(max, max), max);
+    assert_eq!(rng.random_in_sphere(center, 0.0), center);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn points_cover_3d_volumes_uniformly() {
+    let mut rng = RngComponent::with_seed(23456);
+
+    let mut octants = [0usize; 8];
+
+    for _ in 0..SAMPLES {
+        let point = rng.random_in_aabb(Vec3::ZERO, Vec3::new(2.0, 4.0, 6.0));
+
+        let octant = usize::from(point.x > 1.0)
+            | usize::from(point.y > 2.0) << 1
+            | usize::from(point.z > 3.0) << 2;
+
+        octants[octant] += 1;
+    }
+
+    let expected = SAMPLES as f32 / 8.0;
+
+    for count in octants {
+        assert!(
+            (count as f32 - expected).abs() < expected * 0.1,
+            "octants were {:?}",
+            octants
+        );
+    }
+
+    // The inner sphere of half the radius covers an eighth of the volume
+    let inner = (0..SAMPLES)
+        .filter(|_| rng.random_in_sphere(Vec3::ZERO, 2.0).length() < 1.0)
+        .count();
+
+    assert!(
+        (inner as f32 - expected).abs() < expected * 0.1,
+        "{} points within the inner sphere",
+        inner
+    );
+
+    let mean = (0..SAMPLES)
+        .map(|_| rng.random_on_sphere(Vec3::ONE, 1.0))
+        .sum::<Vec3>()
+        / SAMPLES as f32;
+
+    assert!((mean - Vec3::ONE).length() < 0.02, "mean was {:?}", mean);
+}
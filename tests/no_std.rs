@@ -0,0 +1,51 @@
+#![cfg(not(feature = "std"))]
+#![cfg(feature = "wyrand")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn delegated_rng_without_std() {
+    let mut global = GlobalRng::with_seed(12345);
+    let mut component_1 = RngComponent::from(&mut global);
+    let mut component_2 = RngComponent::from(&mut GlobalRng::with_seed(12345));
+
+    assert_eq!(component_1.u64(..), component_2.u64(..));
+
+    let mut list = [1, 2, 3, 4, 5];
+
+    component_1.shuffle(&mut list);
+    component_2.shuffle(&mut [1, 2, 3, 4, 5]);
+
+    assert_eq!(component_1.sample_multiple(&list, 2).len(), 2);
+    assert_eq!(
+        component_1.roll("3x6"),
+        Err(DiceParseError::InvalidModifier)
+    );
+    assert!((1..=6).contains(&component_1.roll("1d6").unwrap()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn plugin_without_std() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345));
+
+    app.world.send_event(ReseedGlobalRng { seed: 54321 });
+    app.update();
+
+    let mut expected = GlobalRng::with_seed(54321);
+
+    assert_eq!(
+        app.world.resource_mut::<GlobalRng>().u64(..),
+        expected.u64(..)
+    );
+}
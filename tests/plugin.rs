@@ -0,0 +1,620 @@
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+fn global_value(app: &mut App) -> u64 {
+    app.world.resource_mut::<GlobalRng>().u64(..)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn seed_from_present_env_var() {
+    std::env::set_var("BEVY_TURBORAND_TEST_SEED_PRESENT", "12345");
+
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_seed_from_env("BEVY_TURBORAND_TEST_SEED_PRESENT"));
+
+    assert_eq!(global_value(&mut app), GlobalRng::with_seed(12345).u64(..));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn seed_from_absent_env_var() {
+    std::env::remove_var("BEVY_TURBORAND_TEST_SEED_ABSENT");
+
+    let mut app_1 = App::new();
+    let mut app_2 = App::new();
+
+    app_1.add_plugin(RngPlugin::new().with_seed_from_env("BEVY_TURBORAND_TEST_SEED_ABSENT"));
+    app_2.add_plugin(RngPlugin::new().with_seed_from_env("BEVY_TURBORAND_TEST_SEED_ABSENT"));
+
+    // Falls back to randomised seeds, so both instances should differ
+    assert_ne!(global_value(&mut app_1), global_value(&mut app_2));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn seed_from_malformed_env_var() {
+    std::env::set_var("BEVY_TURBORAND_TEST_SEED_MALFORMED", "not-a-seed");
+
+    let mut app_1 = App::new();
+    let mut app_2 = App::new();
+
+    app_1.add_plugin(RngPlugin::new().with_seed_from_env("BEVY_TURBORAND_TEST_SEED_MALFORMED"));
+    app_2.add_plugin(RngPlugin::new().with_seed_from_env("BEVY_TURBORAND_TEST_SEED_MALFORMED"));
+
+    assert_ne!(global_value(&mut app_1), global_value(&mut app_2));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn explicit_seed_overrides_env_var() {
+    std::env::set_var("BEVY_TURBORAND_TEST_SEED_OVERRIDE", "12345");
+
+    let mut app = App::new();
+
+    app.add_plugin(
+        RngPlugin::new()
+            .with_rng_seed(54321)
+            .with_seed_from_env("BEVY_TURBORAND_TEST_SEED_OVERRIDE"),
+    );
+
+    assert_eq!(global_value(&mut app), GlobalRng::with_seed(54321).u64(..));
+}
+
+#[test]
+fn reseed_event_restarts_sequence() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345));
+
+    app.world.send_event(ReseedGlobalRng { seed: 20221201 });
+    app.update();
+
+    let first: Vec<u64> = (0..4).map(|_| global_value(&mut app)).collect();
+
+    app.world.send_event(ReseedGlobalRng { seed: 20221201 });
+    app.update();
+
+    let second: Vec<u64> = (0..4).map(|_| global_value(&mut app)).collect();
+
+    assert_eq!(first, second);
+
+    // Reseeding replays the sequence of a fresh GlobalRng with the same seed
+    let mut fresh = GlobalRng::with_seed(20221201);
+    let fresh: Vec<u64> = (0..4).map(|_| fresh.u64(..)).collect();
+
+    assert_eq!(first, fresh);
+}
+
+#[test]
+fn reseed_event_last_sent_wins() {
+    let mut app_1 = App::new();
+    let mut app_2 = App::new();
+
+    app_1.add_plugin(RngPlugin::new());
+    app_2.add_plugin(RngPlugin::new());
+
+    app_1.world.send_event(ReseedGlobalRng { seed: 1 });
+    app_1.world.send_event(ReseedGlobalRng { seed: 2 });
+    app_2.world.send_event(ReseedGlobalRng { seed: 2 });
+
+    app_1.update();
+    app_2.update();
+
+    assert_eq!(global_value(&mut app_1), global_value(&mut app_2));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum GameState {
+    Menu,
+    Dungeon,
+}
+
+fn set_state(app: &mut App, state: GameState) {
+    app.world
+        .resource_mut::<State<GameState>>()
+        .set(state)
+        .unwrap();
+    app.update();
+}
+
+#[test]
+fn reseed_on_enter_restarts_sequence() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+        .add_state(GameState::Menu)
+        .reseed_on_enter(GameState::Dungeon, 777);
+
+    app.update();
+
+    set_state(&mut app, GameState::Dungeon);
+
+    let first: Vec<u64> = (0..4).map(|_| global_value(&mut app)).collect();
+
+    set_state(&mut app, GameState::Menu);
+
+    // Leaving the state doesn't reseed, so the sequence carries on
+    assert_ne!(
+        (0..4).map(|_| global_value(&mut app)).collect::<Vec<_>>(),
+        first
+    );
+
+    set_state(&mut app, GameState::Dungeon);
+
+    let second: Vec<u64> = (0..4).map(|_| global_value(&mut app)).collect();
+
+    assert_eq!(first, second);
+}
+
+#[derive(Debug, Default, Resource)]
+struct Observed(Vec<u64>);
+
+fn observe_global(mut global: ResMut<'_, GlobalRng>, mut observed: ResMut<'_, Observed>) {
+    observed.0.push(global.u64(..));
+}
+
+#[test]
+fn systems_after_reseed_observe_reseeded_state() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+        .init_resource::<Observed>()
+        .add_system_to_stage(CoreStage::First, observe_global.after(RngSystem::Reseed));
+
+    app.world.send_event(ReseedGlobalRng { seed: 20221201 });
+    app.update();
+    app.world.send_event(ReseedGlobalRng { seed: 20221201 });
+    app.update();
+
+    let mut reseeded = GlobalRng::new();
+
+    reseeded.reseed(20221201);
+
+    let expected = reseeded.u64(..);
+
+    assert_eq!(app.world.resource::<Observed>().0, vec![expected, expected]);
+}
+
+fn observe_shared_global(global: Res<'_, GlobalRng>, mut observed: ResMut<'_, Observed>) {
+    observed.0.push(global.get_cell().u64(..));
+}
+
+#[test]
+fn shared_global_draws_advance_state() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+        .init_resource::<Observed>()
+        .add_system(observe_shared_global);
+
+    app.update();
+    app.update();
+
+    let mut expected = GlobalRng::with_seed(12345);
+    let expected: Vec<u64> = (0..3).map(|_| expected.u64(..)).collect();
+
+    assert_eq!(app.world.resource::<Observed>().0, expected[..2]);
+
+    let global = app.world.resource::<GlobalRng>();
+
+    assert_eq!(global, global);
+    assert_eq!(global.get_cell().u64(..), expected[2]);
+}
+
+#[test]
+fn master_seed_reproduces_globals() {
+    let mut app_1 = App::new();
+    let mut app_2 = App::new();
+    let mut app_3 = App::new();
+
+    app_1.add_plugin(RngPlugin::new().with_master_seed(20221201));
+    app_2.add_plugin(RngPlugin::new().with_master_seed(20221201));
+    app_3.add_plugin(RngPlugin::new().with_master_seed(20221202));
+
+    let first: Vec<u64> = (0..4).map(|_| global_value(&mut app_1)).collect();
+    let second: Vec<u64> = (0..4).map(|_| global_value(&mut app_2)).collect();
+
+    assert_eq!(first, second);
+    assert_ne!(
+        (0..4).map(|_| global_value(&mut app_3)).collect::<Vec<_>>(),
+        first
+    );
+
+    #[cfg(feature = "chacha")]
+    {
+        let chacha_value = |app: &mut App| app.world.resource_mut::<GlobalChaChaRng>().u64(..);
+
+        assert_eq!(chacha_value(&mut app_1), chacha_value(&mut app_2));
+        assert_ne!(chacha_value(&mut app_1), chacha_value(&mut app_3));
+    }
+}
+
+#[test]
+fn needs_rng_gains_seeded_component() {
+    fn spawn_entities(mut commands: Commands<'_, '_>) {
+        commands.spawn(NeedsRng);
+        commands.spawn(NeedsRng);
+    }
+
+    let run = || {
+        let mut app = App::new();
+
+        app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+            .add_plugin(RandomOnSpawnPlugin)
+            .add_startup_system(spawn_entities);
+
+        app.update();
+
+        let mut values: Vec<u64> = app
+            .world
+            .query::<&mut RngComponent>()
+            .iter_mut(&mut app.world)
+            .map(|mut rng| rng.u64(..))
+            .collect();
+
+        values.sort_unstable();
+        values
+    };
+
+    let first = run();
+
+    assert_eq!(first.len(), 2);
+    assert_ne!(first[0], first[1]);
+    assert_eq!(first, run());
+}
+
+#[test]
+fn needs_rng_keeps_existing_component() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+        .add_plugin(RandomOnSpawnPlugin);
+
+    let entity = app
+        .world
+        .spawn((NeedsRng, RngComponent::with_seed(777)))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        app.world.get_mut::<RngComponent>(entity).unwrap().u64(..),
+        RngComponent::with_seed(777).u64(..)
+    );
+}
+
+#[test]
+fn deferred_attach_rng_is_deterministic() {
+    #[derive(Debug, Component)]
+    struct Enemy;
+
+    fn spawn_enemies(mut commands: Commands<'_, '_>) {
+        commands.spawn_with_global_rng(Enemy);
+
+        let entity = commands.spawn(Enemy).id();
+
+        commands.attach_rng(entity);
+
+        // Despawned before the command applies, so no seed is drawn for it
+        let despawned = commands.spawn(Enemy).id();
+
+        commands.entity(despawned).despawn();
+        commands.attach_rng(despawned);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+        .add_startup_system(spawn_enemies);
+
+    app.update();
+
+    let mut reference = GlobalRng::with_seed(12345);
+    let mut expected: Vec<u64> = (0..2)
+        .map(|_| RngComponent::from(&mut reference).u64(..))
+        .collect();
+
+    let mut values: Vec<u64> = app
+        .world
+        .query_filtered::<&mut RngComponent, With<Enemy>>()
+        .iter_mut(&mut app.world)
+        .map(|mut rng| rng.u64(..))
+        .collect();
+
+    expected.sort_unstable();
+    values.sort_unstable();
+
+    assert_eq!(values, expected);
+    assert_eq!(global_value(&mut app), reference.u64(..));
+}
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+#[test]
+fn time_seed_produces_valid_global() {
+    let mut app_1 = App::new();
+
+    app_1.add_plugin(RngPlugin::new().with_time_seed());
+
+    std::thread::sleep(std::time::Duration::from_millis(1));
+
+    let mut app_2 = App::new();
+
+    app_2.add_plugin(RngPlugin::new().with_time_seed());
+
+    assert!(app_1.world.contains_resource::<GlobalRng>());
+    assert_ne!(global_value(&mut app_1), global_value(&mut app_2));
+
+    // Explicit seeds take precedence
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_time_seed().with_rng_seed(12345));
+
+    assert_eq!(global_value(&mut app), GlobalRng::with_seed(12345).u64(..));
+}
+
+#[test]
+fn children_are_seeded_from_parent() {
+    #[derive(Component)]
+    struct Child(usize);
+
+    fn spawn_hierarchy(mut commands: Commands) {
+        commands
+            .spawn(RngComponent::with_seed(12345))
+            .with_children(|parent| {
+                parent
+                    .spawn((Child(0), RngComponent::new()))
+                    .with_children(|child| {
+                        child.spawn((Child(2), RngComponent::new()));
+                    });
+                parent.spawn((Child(1), RngComponent::new()));
+            });
+    }
+
+    let mut app = App::new();
+
+    app.add_startup_system(spawn_hierarchy)
+        .add_system_to_stage(CoreStage::PostUpdate, seed_from_parent);
+
+    app.update();
+
+    let mut reference = RngComponent::with_seed(12345);
+    let mut first = RngComponent::from(&mut reference);
+    let second = RngComponent::from(&mut reference);
+    let grandchild = RngComponent::from(&mut first);
+
+    let mut values: Vec<(usize, RngComponent)> = app
+        .world
+        .query::<(&Child, &RngComponent)>()
+        .iter(&app.world)
+        .map(|(child, rng)| (child.0, rng.clone_exact()))
+        .collect();
+
+    values.sort_unstable_by_key(|(index, _)| *index);
+
+    assert_eq!(
+        values,
+        vec![(0, first.clone_exact()), (1, second), (2, grandchild)]
+    );
+
+    // Children are not reseeded again once their parent is unchanged
+    app.update();
+
+    let child = app
+        .world
+        .query::<(&Child, &RngComponent)>()
+        .iter(&app.world)
+        .find(|(child, _)| child.0 == 0)
+        .map(|(_, rng)| rng.clone_exact())
+        .unwrap();
+
+    assert_eq!(child, first);
+}
+
+#[cfg(all(feature = "serialize", feature = "std"))]
+#[test]
+fn diagnostics_count_draws() {
+    use bevy::diagnostic::Diagnostics;
+
+    #[derive(Component)]
+    struct Enemy;
+
+    fn spawn_enemies(mut commands: Commands, mut global: ResMut<GlobalRng>) {
+        commands.spawn((Enemy, RngComponent::from(&mut global)));
+        commands.spawn((Enemy, RngComponent::from(&mut global)));
+    }
+
+    fn attack(mut q_enemies: Query<&mut RngComponent, With<Enemy>>, mut attacks: Local<u64>) {
+        *attacks += 1;
+
+        for mut rng in q_enemies.iter_mut() {
+            for _ in 0..*attacks {
+                rng.u64(..);
+            }
+        }
+    }
+
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+        .add_plugin(RngDiagnosticsPlugin::new())
+        .add_startup_system(spawn_enemies)
+        .add_system(attack);
+
+    let draws = |app: &mut App| -> Vec<u64> {
+        app.world
+            .query::<&RngDrawCount>()
+            .iter(&app.world)
+            .map(RngDrawCount::draws)
+            .collect()
+    };
+
+    // The counters get attached on the first frame, so its draws aren't counted
+    app.update();
+
+    assert_eq!(draws(&mut app), vec![0, 0]);
+
+    app.update();
+
+    assert_eq!(draws(&mut app), vec![2, 2]);
+
+    app.update();
+
+    assert_eq!(draws(&mut app), vec![3, 3]);
+
+    let diagnostics = app.world.resource::<Diagnostics>();
+
+    assert_eq!(
+        diagnostics
+            .get(RngDiagnosticsPlugin::DRAWS)
+            .and_then(|diagnostic| diagnostic.value()),
+        Some(6.0)
+    );
+    assert_eq!(
+        diagnostics
+            .get(RngDiagnosticsPlugin::MAX_DRAWS)
+            .and_then(|diagnostic| diagnostic.value()),
+        Some(3.0)
+    );
+}
+
+#[cfg(all(feature = "serialize", feature = "std"))]
+#[test]
+fn diagnostics_ambiguity_reports_are_opt_in() {
+    use bevy::ecs::schedule::ReportExecutionOrderAmbiguities;
+
+    let mut app = App::new();
+
+    app.add_plugin(RngDiagnosticsPlugin::default());
+
+    assert!(!app
+        .world
+        .contains_resource::<ReportExecutionOrderAmbiguities>());
+
+    let mut app = App::new();
+
+    app.add_plugin(RngDiagnosticsPlugin::new().with_ambiguity_reports());
+
+    assert!(app
+        .world
+        .contains_resource::<ReportExecutionOrderAmbiguities>());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn strict_global_allows_seeding_components() {
+    fn spawn_entities(mut commands: Commands<'_, '_>, mut global: ResMut<'_, GlobalRng>) {
+        commands.spawn(RngComponent::from(&mut global));
+        commands.spawn(NeedsRng);
+        commands.spawn_with_global_rng(());
+    }
+
+    fn use_local(mut rng: LocalRng<'_, '_>) {
+        rng.u64(..);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345).strict_global())
+        .add_plugin(RandomOnSpawnPlugin)
+        .add_startup_system(spawn_entities)
+        .add_system(use_local);
+
+    app.update();
+    app.update();
+
+    assert!(app.world.resource::<GlobalRng>().is_strict());
+    assert_eq!(
+        app.world.query::<&RngComponent>().iter(&app.world).count(),
+        3
+    );
+
+    let mut global = app.world.resource_mut::<GlobalRng>();
+
+    let _ = DelegatedRng::fork(&mut *global);
+    global.reseed(12345);
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[should_panic(expected = "GlobalRng was drawn from directly while in strict mode")]
+fn strict_global_panics_on_direct_draw() {
+    fn roll_damage(mut global: ResMut<'_, GlobalRng>) {
+        global.u32(1..=6);
+    }
+
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345).strict_global())
+        .add_system(roll_damage);
+
+    app.update();
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+fn secure_reseed_interval_refreshes_secure_global() {
+    use bevy::utils::{Duration, Instant};
+
+    let seed = [7; 40];
+    let start = Instant::now();
+    let reference = ChaChaRng::with_seed(seed);
+    let mut app = App::new();
+
+    app.insert_resource(Time::new(start)).add_plugin(
+        RngPlugin::new()
+            .with_chacha_seed(seed)
+            .with_secure_reseed_interval(Duration::from_secs(60)),
+    );
+
+    let advance_to = |app: &mut App, secs: u64| {
+        app.world
+            .resource_mut::<Time>()
+            .update_with_instant(start + Duration::from_secs(secs));
+        app.update();
+
+        app.world.resource_mut::<GlobalChaChaRng>().u64(..)
+    };
+
+    // The first update of the clock doesn't count towards the interval
+    assert_eq!(advance_to(&mut app, 0), reference.u64(..));
+
+    // Before the interval elapses, the seeded sequence continues
+    assert_eq!(advance_to(&mut app, 30), reference.u64(..));
+    assert_eq!(advance_to(&mut app, 59), reference.u64(..));
+
+    // Past the interval, the secure global has been reseeded with fresh entropy
+    assert_ne!(advance_to(&mut app, 61), reference.u64(..));
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Default)]
+struct FaultySource(Rng);
+
+#[cfg(feature = "std")]
+impl DelegatedRng for FaultySource {
+    type Source = Rng;
+
+    fn get_mut(&mut self) -> &mut Self::Source {
+        panic!("faulty source");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn strict_global_survives_panicking_seeding() {
+    let seeded = std::panic::catch_unwind(|| RngComponent::from(&mut FaultySource::default()));
+
+    assert!(seeded.is_err());
+
+    // The panic must not leave the thread in a seeding context
+    let drawn = std::panic::catch_unwind(|| {
+        let mut global = GlobalRng::with_seed(12345);
+
+        global.set_strict(true);
+        global.u64(..)
+    });
+
+    assert!(drawn.is_err());
+}
@@ -0,0 +1,87 @@
+#![cfg(all(feature = "wyrand", feature = "std"))]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn draw(pool: &mut RngPool) -> Vec<u64> {
+    let mut rng = pool.acquire();
+
+    (0..4).map(|_| rng.u64(..)).collect()
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pool_reuses_released_generators() {
+    let mut pool = RngPool::with_seed(12345);
+
+    assert_eq!(pool.available(), 0);
+
+    for _ in 0..10_000 {
+        let mut rng = pool.acquire();
+
+        rng.u64(..);
+    }
+
+    // Only a single generator was ever needed, as each one was released before the next acquire
+    assert_eq!(pool.available(), 1);
+
+    let held: Vec<PooledRng> = (0..3).map(|_| pool.acquire()).collect();
+
+    assert_eq!(pool.available(), 0);
+
+    drop(held);
+
+    assert_eq!(pool.available(), 3);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn pool_sequences_are_deterministic() {
+    let mut pool_1 = RngPool::with_seed(12345);
+    let mut pool_2 = RngPool::with_seed(12345);
+
+    let first = (draw(&mut pool_1), draw(&mut pool_1));
+    let second = (draw(&mut pool_2), draw(&mut pool_2));
+
+    assert_eq!(first, second);
+
+    // A released generator resumes its sequence rather than restarting it
+    assert_ne!(first.0, first.1);
+}
+
+#[derive(Debug, Default, Resource)]
+struct Draws(Vec<u64>);
+
+fn draw_from_local_pool(mut pool: Local<RngPool>, mut draws: ResMut<Draws>) {
+    let mut rng = pool.acquire();
+
+    draws.0.push(rng.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn local_pool_seeds_from_global() {
+    let run = || {
+        let mut app = App::new();
+
+        app.insert_resource(GlobalRng::with_seed(45678))
+            .init_resource::<Draws>()
+            .add_system(draw_from_local_pool);
+
+        app.update();
+        app.update();
+
+        app.world.remove_resource::<Draws>().unwrap().0
+    };
+
+    let draws = run();
+
+    assert_eq!(draws.len(), 2);
+    assert_eq!(draws, run());
+}
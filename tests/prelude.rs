@@ -0,0 +1,40 @@
+#![cfg(feature = "wyrand")]
+
+// Only the prelude is imported, to check that it covers the common usage on its own
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn roll(rng: &mut impl DelegatedRng) -> u32 {
+    rng.u32(1..=6)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn prelude_covers_common_types() {
+    let _plugin = RngPlugin::new().with_rng_seed(12345);
+
+    let mut global = GlobalRng::with_seed(12345);
+    let mut component = RngComponent::from(&mut global);
+
+    assert!((1..=6).contains(&roll(&mut global)));
+    assert!((1..=6).contains(&roll(&mut component)));
+
+    // `TurboRand` methods are usable on the raw `turborand` types
+    let rng = Rng::with_seed(12345);
+
+    assert!(rng.chance(1.0));
+
+    #[cfg(feature = "chacha")]
+    {
+        let mut global = GlobalChaChaRng::with_seed([7; 40]);
+        let mut component = ChaChaRngComponent::from(&mut global);
+
+        assert!((1..=6).contains(&roll(&mut global)));
+        assert!((1..=6).contains(&roll(&mut component)));
+    }
+}
@@ -0,0 +1,186 @@
+#![cfg(feature = "bevy_reflect")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reflected_clone_keeps_state() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let reflected = rng.clone_value();
+
+    let mut target = RngComponent::with_seed(54321);
+
+    target.apply(reflected.as_ref());
+
+    let expected: Vec<u64> = (0..5).map(|_| rng.u64(..)).collect();
+    let received: Vec<u64> = (0..5).map(|_| target.u64(..)).collect();
+
+    assert_eq!(expected, received);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reflect_component_registration() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345));
+
+    let source = app.world.spawn(RngComponent::with_seed(23456)).id();
+    let target = app.world.spawn(RngComponent::with_seed(65432)).id();
+
+    let registry = app.world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let reflect_component = registry
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<RngComponent>())
+        .unwrap();
+
+    let reflected = reflect_component
+        .reflect(&app.world, source)
+        .unwrap()
+        .clone_value();
+
+    reflect_component.apply(&mut app.world, target, reflected.as_ref());
+
+    let mut source_rng = app.world.get::<RngComponent>(source).unwrap().clone_exact();
+    let mut target_rng = app.world.get::<RngComponent>(target).unwrap().clone_exact();
+
+    assert_eq!(source_rng.u64(..), target_rng.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn from_reflect_keeps_state() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let mut copy = RngComponent::from_reflect(rng.as_reflect()).unwrap();
+
+    assert_eq!(rng.u64(..), copy.u64(..));
+}
+
+#[cfg(feature = "chacha")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reflected_secure_clone_keeps_state() {
+    let mut rng = ChaChaRngComponent::with_seed([1; 40]);
+
+    let reflected = rng.clone_value();
+
+    let mut target = ChaChaRngComponent::with_seed([2; 40]);
+
+    target.apply(reflected.as_ref());
+
+    let expected: Vec<u64> = (0..5).map(|_| rng.u64(..)).collect();
+    let received: Vec<u64> = (0..5).map(|_| target.u64(..)).collect();
+
+    assert_eq!(expected, received);
+}
+
+#[derive(Debug, Default, Component, Reflect)]
+#[reflect(Component)]
+struct Health(u32);
+
+fn reflect_component(app: &App) -> ReflectComponent {
+    app.world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<RngComponent>())
+        .unwrap()
+        .clone()
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reloaded_state_overwrites_existing_component() {
+    use bevy::reflect::serde::{ReflectSerializer, UntypedReflectDeserializer};
+    use serde::de::DeserializeSeed;
+
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345));
+
+    let mut saved = RngComponent::with_seed(23456);
+
+    saved.u64(..);
+
+    // Round trip through the reflection serializers, the same as a scene file does
+    let payload = {
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+
+        serde_json::to_string(&ReflectSerializer::new(&saved, &registry)).unwrap()
+    };
+    let reloaded = {
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let mut deserializer = serde_json::Deserializer::from_str(&payload);
+
+        UntypedReflectDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap()
+    };
+
+    let existing = app.world.spawn(RngComponent::with_seed(65432)).id();
+    let missing = app.world.spawn_empty().id();
+
+    let reflect_component = reflect_component(&app);
+
+    reflect_component.apply_or_insert(&mut app.world, existing, reloaded.as_ref());
+    reflect_component.apply_or_insert(&mut app.world, missing, reloaded.as_ref());
+
+    let expected = saved.u64(..);
+
+    for entity in [existing, missing] {
+        assert_eq!(
+            app.world.get_mut::<RngComponent>(entity).unwrap().u64(..),
+            expected
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn reload_without_component_keeps_state() {
+    let mut app = App::new();
+
+    app.add_plugin(RngPlugin::new().with_rng_seed(12345))
+        .register_type::<Health>();
+
+    let entity = app
+        .world
+        .spawn((RngComponent::with_seed(23456), Health(10)))
+        .id();
+
+    app.world.get_mut::<RngComponent>(entity).unwrap().u64(..);
+
+    let reflect_health = app
+        .world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_type_data::<ReflectComponent>(std::any::TypeId::of::<Health>())
+        .unwrap()
+        .clone();
+
+    // The incoming data only carries the other component
+    reflect_health.apply(&mut app.world, entity, &Health(5));
+
+    let mut reference = RngComponent::with_seed(23456);
+
+    reference.u64(..);
+
+    assert_eq!(app.world.get::<Health>(entity).unwrap().0, 5);
+    assert_eq!(
+        app.world.get_mut::<RngComponent>(entity).unwrap().u64(..),
+        reference.u64(..)
+    );
+    assert!(reflect_component(&app)
+        .reflect(&app.world, entity)
+        .is_some());
+}
@@ -0,0 +1,1159 @@
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_iter_matches_weighted_slice_distribution() {
+    const TRIALS: usize = 50_000;
+
+    let values = [1usize, 2, 3, 4];
+    let total = values.iter().sum::<usize>() as f64;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    let mut iter_counts = [0usize; 4];
+    let mut slice_counts = [0usize; 4];
+
+    for _ in 0..TRIALS {
+        let picked = rng
+            .sample_weighted_iter(values.iter().copied(), |&value| value as f64)
+            .unwrap();
+        iter_counts[picked - 1] += 1;
+
+        let picked = rng
+            .weighted_sample(&values, |&value| value as f64 / total)
+            .unwrap();
+        slice_counts[picked - 1] += 1;
+    }
+
+    for (index, (&from_iter, &from_slice)) in iter_counts.iter().zip(&slice_counts).enumerate() {
+        let expected = values[index] as f64 / total;
+        let iter_rate = from_iter as f64 / TRIALS as f64;
+        let slice_rate = from_slice as f64 / TRIALS as f64;
+
+        assert!(
+            (iter_rate - expected).abs() < 0.01,
+            "iter rate {}",
+            iter_rate
+        );
+        assert!(
+            (iter_rate - slice_rate).abs() < 0.015,
+            "slice rate {}",
+            slice_rate
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_iter_edge_cases() {
+    let mut rng = RngComponent::with_seed(23456);
+
+    assert_eq!(
+        rng.sample_weighted_iter(std::iter::empty::<u32>(), |_| 1.0),
+        None
+    );
+    assert_eq!(rng.sample_weighted_iter([1, 2, 3], |_| 0.0), None);
+    assert_eq!(
+        rng.sample_weighted_iter([1, 2, 3], |&value| if value == 2 { 1.0 } else { 0.0 }),
+        Some(2)
+    );
+
+    let mut rng_1 = RngComponent::with_seed(34567);
+    let mut rng_2 = RngComponent::with_seed(34567);
+
+    let first: Vec<_> = (0..10)
+        .map(|_| rng_1.sample_weighted_iter(0..10, |&value| f64::from(value)))
+        .collect();
+    let second: Vec<_> = (0..10)
+        .map(|_| rng_2.sample_weighted_iter(0..10, |&value| f64::from(value)))
+        .collect();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_int_matches_float_distribution() {
+    const TRIALS: usize = 50_000;
+
+    let values = [1_u64, 2, 3, 4];
+    let total = values.iter().sum::<u64>() as f64;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    let mut int_counts = [0_usize; 4];
+    let mut float_counts = [0_usize; 4];
+
+    for _ in 0..TRIALS {
+        let picked = rng.weighted_sample_int(&values, |&value| value).unwrap();
+        int_counts[*picked as usize - 1] += 1;
+
+        let picked = rng
+            .weighted_sample(&values, |&value| value as f64 / total)
+            .unwrap();
+        float_counts[*picked as usize - 1] += 1;
+    }
+
+    for (index, (&from_int, &from_float)) in int_counts.iter().zip(&float_counts).enumerate() {
+        let expected = values[index] as f64 / total;
+        let int_rate = from_int as f64 / TRIALS as f64;
+        let float_rate = from_float as f64 / TRIALS as f64;
+
+        assert!((int_rate - expected).abs() < 0.01, "int rate {}", int_rate);
+        assert!(
+            (int_rate - float_rate).abs() < 0.015,
+            "float rate {}",
+            float_rate
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_int_edge_cases() {
+    let mut rng = RngComponent::with_seed(23456);
+
+    let empty: [u64; 0] = [];
+
+    assert_eq!(rng.weighted_sample_int(&empty, |&value| value), None);
+    assert_eq!(rng.weighted_sample_int(&[1, 2, 3], |_| 0), None);
+    assert_eq!(
+        rng.weighted_sample_int(&[1, 2, 3], |&value| u64::from(value == 2)),
+        Some(&2)
+    );
+
+    // Weights summing past `u64::MAX` don't overflow
+    let huge = [u64::MAX, u64::MAX, 0];
+
+    for _ in 0..100 {
+        assert_ne!(rng.weighted_sample_int(&huge, |&value| value), Some(&0));
+    }
+
+    let mut rng_1 = RngComponent::with_seed(34567);
+    let mut rng_2 = RngComponent::with_seed(34567);
+
+    let list: Vec<u64> = (0..10).collect();
+
+    let first: Vec<_> = (0..10)
+        .map(|_| rng_1.weighted_sample_int(&list, |&value| value))
+        .collect();
+    let second: Vec<_> = (0..10)
+        .map(|_| rng_2.weighted_sample_int(&list, |&value| value))
+        .collect();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_mut_changes_one_element() {
+    let mut rng = RngComponent::with_seed(45678);
+
+    let original = [1u32, 2, 3, 4, 5];
+    let mut values = original;
+
+    let total = f64::from(values.iter().sum::<u32>());
+
+    if let Some(value) = rng.weighted_sample_mut(&mut values, |&value| f64::from(value) / total) {
+        *value += 100;
+    }
+
+    let changed = original
+        .iter()
+        .zip(&values)
+        .filter(|(before, after)| before != after)
+        .count();
+
+    assert_eq!(changed, 1);
+
+    let mut empty: [u32; 0] = [];
+
+    assert_eq!(rng.weighted_sample_mut(&mut empty, |_| 1.0), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_mut_changes_one_element() {
+    let mut rng = RngComponent::with_seed(45678);
+
+    let original = [10u32, 20, 30, 40, 50];
+    let mut health = original;
+
+    if let Some(value) = rng.sample_mut(&mut health) {
+        *value -= 5;
+    }
+
+    let changed = original
+        .iter()
+        .zip(&health)
+        .filter(|(before, after)| before != after)
+        .count();
+
+    assert_eq!(changed, 1);
+
+    let mut rng_1 = RngComponent::with_seed(56789);
+    let mut rng_2 = RngComponent::with_seed(56789);
+    let mut values = original;
+
+    assert_eq!(
+        rng_1.sample_mut(&mut values).copied(),
+        rng_2.sample(&original).copied()
+    );
+
+    let mut empty: [u32; 0] = [];
+
+    assert_eq!(rng.sample_mut(&mut empty), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffle_portable_matches_expected_ordering() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut values: Vec<u32> = (0..10).collect();
+
+    rng.shuffle_portable(&mut values);
+
+    // Hardcoded, as the ordering must not change between platforms or releases
+    assert_eq!(values, vec![8, 6, 7, 4, 3, 0, 1, 5, 9, 2]);
+
+    let mut empty: [u32; 0] = [];
+    let mut single = [1];
+
+    rng.shuffle_portable(&mut empty);
+    rng.shuffle_portable(&mut single);
+
+    assert_eq!(single, [1]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn portable_sampling_matches_expected_indices() {
+    let mut rng = RngComponent::with_seed(12345);
+    let values: Vec<u32> = (0..10).collect();
+
+    let sampled: Vec<u32> = (0..5)
+        .map(|_| *rng.sample_portable(&values).unwrap())
+        .collect();
+    let multiple: Vec<u32> = rng
+        .sample_multiple_portable(&values, 4)
+        .into_iter()
+        .copied()
+        .collect();
+    let weighted: Vec<u32> = (0..5)
+        .map(|_| {
+            *rng.weighted_sample_portable(&values, |&value| f64::from(value) / 10.0)
+                .unwrap()
+        })
+        .collect();
+
+    // Hardcoded, as the selections must not change between platforms or releases
+    assert_eq!(sampled, vec![2, 3, 6, 1, 0]);
+    assert_eq!(multiple, vec![8, 2, 9, 4]);
+    assert_eq!(weighted, vec![5, 6, 6, 7, 6]);
+
+    assert_eq!(rng.sample_portable::<u32>(&[]), None);
+    assert_eq!(rng.sample_multiple_portable(&values, 20).len(), 10);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_index_edge_cases() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(rng.weighted_index(&[]), None);
+    assert_eq!(rng.weighted_index(&[0.0, 0.0, 0.0]), None);
+    assert_eq!(rng.weighted_index(&[-1.0, f64::NAN]), None);
+    assert_eq!(rng.weighted_index(&[3.0]), Some(0));
+    assert_eq!(rng.weighted_index(&[0.0, -5.0, 1.0, 0.0]), Some(2));
+    assert_eq!(rng.weighted_index(&[1.0, f64::INFINITY, 1.0]), Some(1));
+    assert_eq!(rng.weighted_index(&[0.0, f64::MAX]), Some(1));
+    assert!(rng.weighted_index(&[f64::MAX, f64::MAX]).is_some());
+
+    // Finite weights overflowing their sum are still picked proportionally
+    let mut counts = [0_usize; 3];
+
+    for _ in 0..10_000 {
+        counts[rng
+            .weighted_index(&[f64::MAX, f64::MAX, f64::NEG_INFINITY])
+            .unwrap()] += 1;
+    }
+
+    assert_eq!(counts[2], 0);
+    assert!((4_500..=5_500).contains(&counts[0]), "{:?}", counts);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_index_distribution() {
+    const TRIALS: usize = 50_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+    let weights = [1.0, 0.0, 2.0, 7.0];
+    let mut counts = [0_usize; 4];
+
+    for _ in 0..TRIALS {
+        counts[rng.weighted_index(&weights).unwrap()] += 1;
+    }
+
+    assert_eq!(counts[1], 0);
+
+    for (count, weight) in counts.iter().zip(weights) {
+        let expected = weight / 10.0;
+        let actual = *count as f64 / TRIALS as f64;
+
+        assert!(
+            (actual - expected).abs() < 0.01,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+}
+
+fn generic_range<T: SampleInteger + std::fmt::Debug>(rng: &mut RngComponent, low: T, high: T) {
+    for _ in 0..1000 {
+        let value = rng.gen_range(low..=high);
+
+        assert!(value >= low && value <= high, "{:?} out of range", value);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn gen_range_covers_integer_widths() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    generic_range(&mut rng, 3_u8, 18);
+    generic_range(&mut rng, -300_i16, 300);
+    generic_range(&mut rng, 0_u32, 1);
+    generic_range(&mut rng, i64::MIN, -1);
+    generic_range(&mut rng, u128::MAX - 10, u128::MAX);
+    generic_range(&mut rng, -5_i128, 5);
+
+    let mut rng_1 = RngComponent::with_seed(12345);
+    let mut rng_2 = RngComponent::with_seed(12345);
+
+    assert_eq!(rng_1.gen_range(..100_u32), rng_2.u32(..100));
+    assert_eq!(rng_1.gen_range(-50_i8..50), rng_2.i8(-50..50));
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_multiple_distinct_and_biased() {
+    const TRIALS: usize = 10_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    let list: Vec<u32> = (1..=10).collect();
+    let mut counts = [0_usize; 10];
+
+    for _ in 0..TRIALS {
+        let sampled = rng.weighted_sample_multiple(&list, 3, |&value| f64::from(value));
+
+        assert_eq!(sampled.len(), 3);
+        assert!(sampled.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for &value in sampled {
+            counts[value as usize - 1] += 1;
+        }
+    }
+
+    // Heavier items should be picked more often, though without replacement the bias
+    // flattens out, so only compare items that are far enough apart
+    assert!(counts[9] > counts[4], "{:?}", counts);
+    assert!(counts[4] > counts[0], "{:?}", counts);
+    assert!(counts[0] > 0, "{:?}", counts);
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_multiple_edge_cases() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let list = [1, 2, 3, 4, 5];
+
+    assert_eq!(
+        rng.weighted_sample_multiple(&list, 10, |_| 1.0),
+        list.iter().collect::<Vec<_>>()
+    );
+    assert!(rng.weighted_sample_multiple(&list, 0, |_| 1.0).is_empty());
+    assert!(rng
+        .weighted_sample_multiple(&[] as &[u32], 2, |_| 1.0)
+        .is_empty());
+
+    // All-zero weights fall back to uniform sampling
+    let sampled = rng.weighted_sample_multiple(&list, 3, |_| 0.0);
+
+    assert_eq!(sampled.len(), 3);
+    assert!(sampled.windows(2).all(|pair| pair[0] < pair[1]));
+
+    // Validly weighted items are always picked before the rest
+    for _ in 0..100 {
+        let sampled = rng.weighted_sample_multiple(&list, 3, |&value| {
+            if value % 2 == 0 {
+                f64::from(value)
+            } else {
+                f64::NAN
+            }
+        });
+
+        assert!(sampled.contains(&&2) && sampled.contains(&&4));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_sample_multiple_keeps_slice_order() {
+    const TRIALS: usize = 10_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    // Deliberately unsorted, so that slice order differs from value and weight order
+    let list = [
+        ("gem", 1.0),
+        ("gold", 50.0),
+        ("sword", 5.0),
+        ("potion", 20.0),
+    ];
+    let mut counts = [0_usize; 4];
+
+    for _ in 0..TRIALS {
+        let sampled = rng.weighted_sample_multiple(&list, 2, |&(_, weight)| weight);
+        let positions: Vec<usize> = sampled
+            .iter()
+            .map(|&item| list.iter().position(|other| other == item).unwrap())
+            .collect();
+
+        assert_eq!(positions.len(), 2);
+        assert!(positions[0] < positions[1], "{:?}", positions);
+
+        for position in positions {
+            counts[position] += 1;
+        }
+    }
+
+    assert!(counts[1] > counts[3], "{:?}", counts);
+    assert!(counts[3] > counts[2], "{:?}", counts);
+    assert!(counts[2] > counts[0], "{:?}", counts);
+
+    // The same seed always gives the same ordered result
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    for _ in 0..10 {
+        assert_eq!(
+            rng_1.weighted_sample_multiple(&list, 3, |&(_, weight)| weight),
+            rng_2.weighted_sample_multiple(&list, 3, |&(_, weight)| weight)
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn index_and_indices_edge_cases() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(rng.index(0), None);
+    assert_eq!(rng.index(1), Some(0));
+    assert!((0..100).all(|_| rng.index(7).unwrap() < 7));
+
+    assert!(rng.indices(0, 3).is_empty());
+    assert!(rng.indices(10, 0).is_empty());
+
+    let mut all = rng.indices(5, 10);
+
+    all.sort_unstable();
+
+    assert_eq!(all, vec![0, 1, 2, 3, 4]);
+
+    let mut some = rng.indices(100, 10);
+
+    assert_eq!(some.len(), 10);
+    assert!(some.iter().all(|&index| index < 100));
+
+    some.sort_unstable();
+    some.dedup();
+
+    assert_eq!(some.len(), 10);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_indexed_points_to_item() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut reference = RngComponent::with_seed(12345);
+
+    let list = ['a', 'b', 'c', 'd', 'e', 'f'];
+
+    for _ in 0..20 {
+        let (index, item) = rng.sample_indexed(&list).unwrap();
+
+        assert!(core::ptr::eq(item, &list[index]));
+        assert_eq!(Some(index), reference.index(list.len()));
+    }
+
+    assert_eq!(rng.sample_indexed(&[] as &[char]), None);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_index_and_indices() {
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let first: Vec<_> = (0..10).map(|_| rng_1.index(50)).collect();
+    let second: Vec<_> = (0..10).map(|_| rng_2.index(50)).collect();
+
+    assert_eq!(first, second);
+    assert_eq!(rng_1.indices(50, 8), rng_2.indices(50, 8));
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_shuffle_favours_heavy_items() {
+    const TRIALS: usize = 5_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+    let mut positions = [0_usize; 6];
+
+    for _ in 0..TRIALS {
+        let mut list = [1_u32, 2, 3, 4, 5, 6];
+
+        rng.weighted_shuffle(&mut list, |&value| f64::from(value).powi(2));
+
+        let mut sorted = list;
+
+        sorted.sort_unstable();
+
+        assert_eq!(sorted, [1, 2, 3, 4, 5, 6]);
+
+        for (position, &value) in list.iter().enumerate() {
+            positions[value as usize - 1] += position;
+        }
+    }
+
+    // Heavier items should on average end up earlier
+    assert!(
+        positions.windows(2).all(|pair| pair[0] > pair[1]),
+        "{:?}",
+        positions
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_shuffle_places_invalid_weights_last() {
+    let mut rng = RngComponent::with_seed(23456);
+
+    for _ in 0..100 {
+        let mut list = [0_u32, 1, 2, 3, 4, 5, 6, 7];
+
+        rng.weighted_shuffle(&mut list, |&value| {
+            if value % 2 == 0 {
+                f64::from(value + 1)
+            } else {
+                -1.0
+            }
+        });
+
+        assert!(list[..4].iter().all(|value| value % 2 == 0), "{:?}", list);
+        assert!(list[4..].iter().all(|value| value % 2 == 1), "{:?}", list);
+    }
+
+    let mut rng_1 = RngComponent::with_seed(34567);
+    let mut rng_2 = RngComponent::with_seed(34567);
+
+    let mut first: Vec<u32> = (0..20).collect();
+    let mut second = first.clone();
+
+    rng_1.weighted_shuffle(&mut first, |_| 0.0);
+    rng_2.weighted_shuffle(&mut second, |_| 0.0);
+
+    assert_eq!(first, second);
+    assert_ne!(first, (0..20).collect::<Vec<_>>());
+
+    rng_1.weighted_shuffle(&mut [] as &mut [u32], |_| 1.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float_ranges_stay_within_bounds() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    for _ in 0..10_000 {
+        assert!((-10.0..=35.0).contains(&rng.f64_range(-10.0..=35.0)));
+        assert!((0.25..=0.5).contains(&rng.f32_range(0.25..=0.5)));
+        assert!((f64::MIN..=f64::MAX).contains(&rng.f64_range(f64::MIN..=f64::MAX)));
+        assert!((f32::MIN..=f32::MAX).contains(&rng.f32_range(f32::MIN..=f32::MAX)));
+    }
+
+    assert_eq!(rng.f64_range(4.5..=4.5), 4.5);
+    assert_eq!(rng.f32_range(-2.0..=-2.0), -2.0);
+
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    assert_eq!(rng_1.f64_range(0.0..=1.0), rng_2.f64_range(0.0..=1.0));
+}
+
+#[test]
+#[should_panic]
+fn inverted_float_range_panics() {
+    RngComponent::with_seed(12345).f64_range(1.0..=0.0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn jitter_stays_within_amount() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut global = GlobalRng::with_seed(12345);
+
+    for _ in 0..10_000 {
+        assert!((9.5..=10.5).contains(&rng.jitter_f64(10.0, 0.5)));
+        assert!((-1.25..=-0.75).contains(&rng.jitter_f32(-1.0, 0.25)));
+        assert!((99.0..=101.0).contains(&global.jitter_f32(100.0, 1.0)));
+    }
+
+    assert_eq!(rng.jitter_f64(4.5, 0.0), 4.5);
+    assert_eq!(rng.jitter_f32(-2.0, 0.0), -2.0);
+
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    for _ in 0..10 {
+        assert_eq!(rng_1.jitter_f64(1.0, 0.1), rng_2.jitter_f64(1.0, 0.1));
+        assert_eq!(rng_1.jitter_f32(1.0, 0.1), rng_2.jitter_f32(1.0, 0.1));
+    }
+}
+
+#[test]
+#[should_panic]
+fn negative_jitter_panics() {
+    RngComponent::with_seed(12345).jitter_f32(1.0, -0.5);
+}
+
+#[test]
+#[should_panic(expected = "jitter base and amount must be finite")]
+fn non_finite_jitter_panics() {
+    RngComponent::with_seed(12345).jitter_f64(1.0, f64::INFINITY);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn overflowing_jitter_stays_finite() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    for _ in 0..1_000 {
+        assert!(rng.jitter_f64(f64::MAX, f64::MAX).is_finite());
+        assert!(rng.jitter_f64(f64::MIN, f64::MAX).is_finite());
+        assert!(rng.jitter_f32(f32::MAX, f32::MAX).is_finite());
+        assert!(rng.jitter_f32(f32::MIN, f32::MAX).is_finite());
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn open_floats_exclude_both_endpoints() {
+    const TRIALS: usize = 100_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+
+    let mut sum_64 = 0.0;
+    let mut sum_32 = 0.0;
+
+    for _ in 0..TRIALS {
+        let value_64 = rng.f64_open();
+        let value_32 = rng.f32_open();
+
+        assert!(value_64 > 0.0 && value_64 < 1.0, "{}", value_64);
+        assert!(value_32 > 0.0 && value_32 < 1.0, "{}", value_32);
+        assert!(value_64.ln().is_finite());
+
+        sum_64 += value_64;
+        sum_32 += f64::from(value_32);
+    }
+
+    let mean_64 = sum_64 / TRIALS as f64;
+    let mean_32 = sum_32 / TRIALS as f64;
+
+    assert!((mean_64 - 0.5).abs() < 0.01, "mean was {}", mean_64);
+    assert!((mean_32 - 0.5).abs() < 0.01, "mean was {}", mean_32);
+
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    assert_eq!(rng_1.f64_open(), rng_2.f64_open());
+    assert_eq!(rng_1.f32_open(), rng_2.f32_open());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_distinct_small_is_distinct_and_uniform() {
+    const TRIALS: usize = 10_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+    let list: Vec<usize> = (0..64).collect();
+    let mut counts = [0_usize; 64];
+
+    for _ in 0..TRIALS {
+        let sampled = rng.sample_distinct_small(&list, 8);
+
+        assert_eq!(sampled.len(), 8);
+        assert!(!sampled.spilled());
+
+        let mut unique: Vec<usize> = sampled.iter().map(|&&value| value).collect();
+
+        unique.sort_unstable();
+        unique.dedup();
+
+        assert_eq!(unique.len(), 8);
+
+        for &&value in &sampled {
+            counts[value] += 1;
+        }
+    }
+
+    // Every item should be sampled around 1 in 8 times
+    assert!(
+        counts.iter().all(|&count| (1_050..=1_450).contains(&count)),
+        "{:?}",
+        counts
+    );
+
+    let mut all: Vec<usize> = rng
+        .sample_distinct_small(&list[..5], 10)
+        .into_iter()
+        .copied()
+        .collect();
+
+    all.sort_unstable();
+
+    assert_eq!(all, [0, 1, 2, 3, 4]);
+    assert!(rng.sample_distinct_small(&list, 0).is_empty());
+    assert!(rng.sample_distinct_small::<u8>(&[], 3).is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_distinct_small_falls_back_for_large_lists() {
+    let mut rng = RngComponent::with_seed(12345);
+    let list: Vec<usize> = (0..100).collect();
+
+    let mut sampled: Vec<usize> = rng
+        .sample_distinct_small(&list, 90)
+        .into_iter()
+        .copied()
+        .collect();
+
+    assert_eq!(sampled.len(), 90);
+
+    sampled.sort_unstable();
+    sampled.dedup();
+
+    assert_eq!(sampled.len(), 90);
+    assert!(sampled.iter().all(|&value| value < 100));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_sample_distinct_small() {
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let small: Vec<u32> = (0..20).collect();
+    let large: Vec<u32> = (0..200).collect();
+
+    for amount in [1, 5, 20] {
+        assert_eq!(
+            rng_1.sample_distinct_small(&small, amount),
+            rng_2.sample_distinct_small(&small, amount)
+        );
+        assert_eq!(
+            rng_1.sample_distinct_small(&large, amount),
+            rng_2.sample_distinct_small(&large, amount)
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffle_with_indices_replays_on_copy() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    for len in [0, 1, 2, 10, 52] {
+        let original: Vec<String> = (0..len).map(|card| card.to_string()).collect();
+        let mut shuffled = original.clone();
+        let mut replayed = original.clone();
+
+        let permutation = rng.shuffle_with_indices(&mut shuffled);
+
+        for (position, &index) in permutation.iter().enumerate() {
+            assert_eq!(shuffled[position], original[index]);
+        }
+
+        apply_permutation(&mut replayed, &permutation);
+
+        assert_eq!(replayed, shuffled);
+    }
+
+    // Shuffles the same way as the portable shuffle
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let mut deck_1: Vec<u32> = (0..52).collect();
+    let mut deck_2 = deck_1.clone();
+
+    rng_1.shuffle_with_indices(&mut deck_1);
+    rng_2.shuffle_portable(&mut deck_2);
+
+    assert_eq!(deck_1, deck_2);
+    assert_eq!(rng_1, rng_2);
+}
+
+#[test]
+#[should_panic(expected = "permutation must contain every index of the slice exactly once")]
+fn apply_invalid_permutation_panics() {
+    apply_permutation(&mut [1, 2, 3], &[0, 2, 2]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn shuffle_partial_draws_valid_sample() {
+    const TRIALS: usize = 10_000;
+
+    let mut rng = RngComponent::with_seed(12345);
+    let mut counts = [0_usize; 10];
+
+    for _ in 0..TRIALS {
+        let mut deck: Vec<usize> = (0..10).collect();
+
+        rng.shuffle_partial(&mut deck, 3);
+
+        let mut hand = deck[..3].to_vec();
+
+        hand.sort_unstable();
+        hand.dedup();
+
+        assert_eq!(hand.len(), 3);
+
+        for &card in &deck[..3] {
+            counts[card] += 1;
+        }
+
+        deck.sort_unstable();
+
+        assert_eq!(deck, (0..10).collect::<Vec<usize>>());
+    }
+
+    // Every card should land in the hand around 30% of the time
+    assert!(
+        counts.iter().all(|&count| (2_700..=3_300).contains(&count)),
+        "{:?}",
+        counts
+    );
+
+    let mut deck = [1, 2, 3];
+
+    rng.shuffle_partial(&mut deck, 10);
+
+    deck.sort_unstable();
+
+    assert_eq!(deck, [1, 2, 3]);
+
+    let mut empty: [u8; 0] = [];
+
+    rng.shuffle_partial(&mut empty, 2);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_shuffle_partial() {
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let mut deck: Vec<usize> = (0..52).collect();
+
+    rng_1.shuffle_partial(&mut deck, 5);
+
+    // Runs the same partial Fisher-Yates as drawing indices
+    assert_eq!(deck[..5], rng_2.indices(52, 5)[..]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_multiple_into_reuses_buffer() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut reference = RngComponent::with_seed(12345);
+
+    let list: Vec<u32> = (0..20).collect();
+    let other = [100_u32, 200];
+    let mut buffer = Vec::new();
+
+    rng.sample_multiple_into(&list, &mut buffer, 5);
+
+    assert_eq!(buffer, reference.sample_multiple(&list, 5));
+
+    let capacity = buffer.capacity();
+
+    // Fewer items than before, with no stale entries left over
+    rng.sample_multiple_into(&other, &mut buffer, 5);
+
+    assert_eq!(buffer, reference.sample_multiple(&other, 5));
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(buffer.capacity(), capacity);
+
+    rng.sample_multiple_into(&list, &mut buffer, 3);
+
+    assert_eq!(buffer, reference.sample_multiple(&list, 3));
+
+    rng.sample_multiple_into(&list, &mut buffer, 0);
+
+    assert!(buffer.is_empty());
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill_bools_matches_probability() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut mask = vec![false; 10_001];
+
+    rng.fill_bools(&mut mask, 0.3);
+
+    let filled = mask.iter().filter(|&&entry| entry).count();
+
+    assert!(
+        (2_700..=3_300).contains(&filled),
+        "{} entries filled",
+        filled
+    );
+
+    let mut reference = RngComponent::with_seed(12345);
+    let mut expected = vec![false; 10_001];
+
+    reference.fill_bools(&mut expected, 0.3);
+
+    assert_eq!(mask, expected);
+    assert_eq!(rng.u64(..), reference.u64(..));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn fill_bools_edge_probabilities() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut reference = RngComponent::with_seed(12345);
+
+    let mut mask = [true; 33];
+
+    rng.fill_bools(&mut mask, 0.0);
+
+    assert!(mask.iter().all(|&entry| !entry));
+
+    rng.fill_bools(&mut mask, 1.0);
+
+    assert!(mask.iter().all(|&entry| entry));
+
+    // Neither extreme draws from the RNG
+    assert_eq!(rng.u64(..), reference.u64(..));
+
+    rng.fill_bools(&mut [], 0.5);
+
+    // Tiny probabilities resolve to never being true, but draw like any other probability
+    let mut tiny = [true; 33];
+    let mut half = [false; 33];
+
+    rng.fill_bools(&mut tiny, 1e-12);
+    reference.fill_bools(&mut half, 0.5);
+
+    assert!(tiny.iter().all(|&entry| !entry));
+    assert_eq!(rng, reference);
+
+    // Just below `2^-32`, the probability rounds up to a non-zero threshold
+    let mut rare = [false; 1_000];
+
+    rng.fill_bools(&mut rare, 0.75 / (1_u64 << 32) as f64);
+    reference.fill_bools(&mut [false; 1_000], 0.5);
+
+    assert_eq!(rng, reference);
+}
+
+#[test]
+#[should_panic]
+fn fill_bools_invalid_probability_panics() {
+    RngComponent::with_seed(12345).fill_bools(&mut [false; 4], 1.5);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn retry_until_succeeds_or_exhausts() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    // Immediate success only generates a single candidate
+    let mut attempts = 0;
+
+    assert_eq!(
+        rng.retry_until(
+            |rng| {
+                attempts += 1;
+                rng.u32(0..10)
+            },
+            |_| true,
+            10
+        )
+        .map(|value| value < 10),
+        Some(true)
+    );
+    assert_eq!(attempts, 1);
+
+    // Eventual success returns the first passing candidate
+    let mut attempts = 0;
+
+    let found = rng.retry_until(
+        |_| {
+            attempts += 1;
+            attempts
+        },
+        |&attempt| attempt == 4,
+        10,
+    );
+
+    assert_eq!(found, Some(4));
+    assert_eq!(attempts, 4);
+
+    let even = rng.retry_until(|rng| rng.u32(..), |&value| value % 2 == 0, 1_000);
+
+    assert!(even.is_some_and(|value| value % 2 == 0));
+
+    // Exhaustion stops after the given number of attempts
+    let mut attempts = 0;
+
+    assert_eq!(
+        rng.retry_until(
+            |rng| {
+                attempts += 1;
+                rng.u32(0..10)
+            },
+            |&value| value > 10,
+            25
+        ),
+        None
+    );
+    assert_eq!(attempts, 25);
+    assert_eq!(rng.retry_until(|rng| rng.u32(..), |_| true, 0), None);
+
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    assert_eq!(
+        rng_1.retry_until(|rng| rng.u32(..), |&value| value % 7 == 0, 100),
+        rng_2.retry_until(|rng| rng.u32(..), |&value| value % 7 == 0, 100)
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn wide_integer_ranges() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    // Full range draws fill both halves of the 128 bits
+    let values: Vec<u128> = (0..100).map(|_| rng.u128(..)).collect();
+
+    assert!(values.iter().any(|&value| value > u128::from(u64::MAX)));
+    assert!(values.iter().any(|&value| value as u64 > u64::MAX / 2));
+
+    let values: Vec<i128> = (0..100).map(|_| rng.i128(..)).collect();
+
+    assert!(values.iter().any(|&value| value < i128::from(i64::MIN)));
+    assert!(values.iter().any(|&value| value > i128::from(i64::MAX)));
+
+    // Small ranges stay within bounds and reach every value
+    let mut seen_unsigned = [false; 3];
+    let mut seen_signed = [false; 7];
+
+    for _ in 0..1_000 {
+        let value = rng.u128(5..8);
+
+        assert!((5..8).contains(&value));
+        seen_unsigned[(value - 5) as usize] = true;
+
+        let value = rng.i128(-3..=3);
+
+        assert!((-3..=3).contains(&value));
+        seen_signed[(value + 3) as usize] = true;
+    }
+
+    assert!(seen_unsigned.iter().all(|&seen| seen));
+    assert!(seen_signed.iter().all(|&seen| seen));
+
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    for _ in 0..10 {
+        assert_eq!(rng_1.u128(..), rng_2.u128(..));
+        assert_eq!(rng_1.i128(-1_000..1_000), rng_2.i128(-1_000..1_000));
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn sample_or_falls_back_on_empty() {
+    let mut rng = RngComponent::with_seed(Default::default());
+    let mut reference = RngComponent::with_seed(Default::default());
+
+    let list = [1, 2, 3, 4, 5];
+    let empty: [i32; 0] = [];
+
+    for _ in 0..10 {
+        let sampled = rng.sample_or(&list, 0);
+
+        assert!(list.contains(&sampled));
+        assert_eq!(Some(&sampled), reference.sample(&list));
+    }
+
+    assert_eq!(rng.sample_or(&empty, 42), 42);
+    assert_eq!(
+        rng.sample_or(&[String::from("only")], String::new()),
+        "only"
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn permutation_leaves_original_untouched() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    let original: Vec<u32> = (0..52).collect();
+
+    let permuted = rng.permutation(&original);
+
+    assert_eq!(original, (0..52).collect::<Vec<u32>>());
+    assert_ne!(permuted, original);
+
+    let mut sorted = permuted.clone();
+
+    sorted.sort_unstable();
+
+    assert_eq!(sorted, original);
+    assert!(rng.permutation::<u32>(&[]).is_empty());
+
+    // Deterministic for a fixed seed, matching the portable shuffle
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+    let mut shuffled = original.clone();
+
+    rng_2.shuffle_portable(&mut shuffled);
+
+    assert_eq!(rng_1.permutation(&original), shuffled);
+}
@@ -0,0 +1,41 @@
+#![cfg(all(feature = "rand", feature = "chacha"))]
+
+use bevy_turborand::prelude::*;
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+fn generate_key<R: RngCore + CryptoRng>(rng: &mut R) -> ([u8; 32], u64) {
+    let mut key = [0; 32];
+    rng.fill_bytes(&mut key);
+    (key, rng.next_u64())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn secure_rand_is_deterministic_with_seed() {
+    let mut global = GlobalChaChaRng::with_seed([7; 40]);
+    let mut component = ChaChaRngComponent::with_seed([7; 40]);
+
+    let key_1 = generate_key(&mut global.as_secure_rand());
+    let key_2 = generate_key(&mut component.as_secure_rand());
+
+    assert_eq!(key_1, key_2);
+    assert_ne!(key_1, generate_key(&mut global.as_secure_rand()));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn secure_rand_matches_source() {
+    let mut component = ChaChaRngComponent::with_seed([7; 40]);
+    let source = ChaChaRng::with_seed([7; 40]);
+
+    let mut rng = component.as_secure_rand();
+
+    assert_eq!(rng.next_u32(), source.gen_u32());
+    assert_eq!(rng.next_u64(), source.gen_u64());
+}
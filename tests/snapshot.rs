@@ -0,0 +1,137 @@
+#![cfg(feature = "serialize")]
+
+use bevy::prelude::*;
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn snapshot_round_trip() {
+    let mut world = World::new();
+
+    world.insert_resource(GlobalRng::with_seed(12345));
+
+    #[cfg(feature = "chacha")]
+    world.insert_resource(GlobalChaChaRng::with_seed([3; 40]));
+
+    let snapshot = RngSnapshot::capture(&world);
+
+    let payload = serde_json::to_string(&snapshot).unwrap();
+
+    let expected: Vec<u64> = (0..5)
+        .map(|_| world.resource_mut::<GlobalRng>().u64(..))
+        .collect();
+
+    #[cfg(feature = "chacha")]
+    let expected_secure: Vec<u64> = (0..5)
+        .map(|_| world.resource_mut::<GlobalChaChaRng>().u64(..))
+        .collect();
+
+    let loaded: RngSnapshot = serde_json::from_str(&payload).unwrap();
+
+    assert_eq!(loaded, snapshot);
+
+    loaded.restore(&mut world).unwrap();
+
+    let restored: Vec<u64> = (0..5)
+        .map(|_| world.resource_mut::<GlobalRng>().u64(..))
+        .collect();
+
+    assert_eq!(expected, restored);
+
+    #[cfg(feature = "chacha")]
+    {
+        let restored_secure: Vec<u64> = (0..5)
+            .map(|_| world.resource_mut::<GlobalChaChaRng>().u64(..))
+            .collect();
+
+        assert_eq!(expected_secure, restored_secure);
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn snapshot_rejects_unknown_version() {
+    let mut world = World::new();
+
+    let snapshot: RngSnapshot =
+        serde_json::from_str("{\"version\":999,\"rng\":{\"state\":24691}}").unwrap();
+
+    assert_eq!(
+        snapshot.restore(&mut world),
+        Err(RngSnapshotError::UnsupportedVersion(999))
+    );
+    assert!(world.get_resource::<GlobalRng>().is_none());
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn serializable_state_round_trip() {
+    let mut global = GlobalRng::with_seed(12345);
+    let mut component = RngComponent::with_seed(54321);
+
+    global.u64(..);
+
+    let payload = serde_json::to_string(&SerializableRngState::from(&global)).unwrap();
+    let component_payload = serde_json::to_string(&SerializableRngState::from(&component)).unwrap();
+
+    let expected: Vec<u64> = (0..5).map(|_| global.u64(..)).collect();
+    let expected_component: Vec<u64> = (0..5).map(|_| component.u64(..)).collect();
+
+    let state: SerializableRngState = serde_json::from_str(&payload).unwrap();
+
+    assert_eq!(state.version(), SerializableRngState::VERSION);
+
+    let mut restored = GlobalRng::try_from(state).unwrap();
+    let mut restored_component = RngComponent::try_from(
+        serde_json::from_str::<SerializableRngState>(&component_payload).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        (0..5).map(|_| restored.u64(..)).collect::<Vec<_>>(),
+        expected
+    );
+    assert_eq!(
+        (0..5)
+            .map(|_| restored_component.u64(..))
+            .collect::<Vec<_>>(),
+        expected_component
+    );
+}
+
+#[cfg(feature = "wyrand")]
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn serializable_state_rejects_unknown_version() {
+    let payload =
+        serde_json::to_string(&SerializableRngState::from(&GlobalRng::with_seed(1))).unwrap();
+
+    let mutated = payload.replace(
+        &format!("\"version\":{}", SerializableRngState::VERSION),
+        "\"version\":999",
+    );
+
+    assert_ne!(mutated, payload);
+
+    let state: SerializableRngState = serde_json::from_str(&mutated).unwrap();
+
+    assert_eq!(
+        state.migrate(),
+        Err(SerializableRngStateError::UnsupportedVersion(999))
+    );
+    assert_eq!(
+        GlobalRng::try_from(state).unwrap_err(),
+        SerializableRngStateError::UnsupportedVersion(999)
+    );
+    assert_eq!(
+        SerializableRngStateError::UnsupportedVersion(999).to_string(),
+        "unsupported SerializableRngState version 999, expected 1 or older"
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(feature = "rng_stats")]
+
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn draws_match_number_of_calls() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(rng.draws(), 0);
+
+    for _ in 0..10 {
+        rng.u64(..);
+    }
+
+    assert_eq!(rng.draws(), 10);
+
+    // Draws made through the internal source count too
+    rng.get_mut().gen_u64();
+    rng.f64();
+
+    assert_eq!(rng.draws(), 12);
+
+    let mut global = GlobalRng::new();
+
+    for _ in 0..1_000 {
+        global.bool();
+    }
+
+    assert_eq!(global.draws(), 1_000);
+
+    // Seeding a component draws once from its source
+    let mut child = RngComponent::from(&mut global);
+
+    assert_eq!(global.draws(), 1_001);
+    assert_eq!(child.draws(), 0);
+
+    child.skip(5);
+
+    assert_eq!(child.draws(), 5);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn draws_survive_reseeding_but_not_restoring() {
+    let mut rng = GlobalRng::with_seed(12345);
+
+    rng.u64(..);
+    rng.u64(..);
+    rng.reseed(67890);
+
+    assert_eq!(rng.draws(), 2);
+
+    rng.u64(..);
+
+    assert_eq!(rng.draws(), 3);
+
+    let restored: GlobalRng = serde_json::from_str(&serde_json::to_string(&rng).unwrap()).unwrap();
+
+    assert_eq!(restored, rng);
+    assert_eq!(restored.draws(), 0);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn draw_stats_are_left_out_of_saves() {
+    let mut rng = RngComponent::with_seed(12345);
+
+    assert_eq!(serde_json::to_string(&rng).unwrap(), "{\"state\":24691}");
+    assert_eq!(
+        serde_json::to_string(&GlobalRng::with_seed(12345)).unwrap(),
+        "{\"state\":24691}"
+    );
+
+    rng.u64(..);
+
+    // Stats don't affect equality either
+    let mut fresh: RngComponent =
+        serde_json::from_str(&serde_json::to_string(&rng).unwrap()).unwrap();
+
+    assert_eq!(fresh, rng);
+    assert_ne!(fresh.draws(), rng.draws());
+    assert_eq!(fresh.u64(..), rng.u64(..));
+}
+
+fn reseed_generic<R: DelegatedRng<Source = Rng>>(rng: &mut R, seed: u64) {
+    rng.reseed(seed);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn draws_survive_reseeding_through_trait() {
+    let mut rng = RngComponent::with_seed(12345);
+    let mut global = GlobalRng::with_seed(12345);
+
+    rng.u64(..);
+    global.u64(..);
+    global.u64(..);
+
+    reseed_generic(&mut rng, 67890);
+    <GlobalRng as DelegatedRng>::reseed(&mut global, 67890);
+
+    assert_eq!(rng.draws(), 1);
+    assert_eq!(global.draws(), 2);
+
+    rng.u64(..);
+    global.u64(..);
+
+    assert_eq!(rng.draws(), 2);
+    assert_eq!(global.draws(), 3);
+}
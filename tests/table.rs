@@ -0,0 +1,84 @@
+use bevy_turborand::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_table_matches_naive_distribution() {
+    const TRIALS: usize = 100_000;
+
+    let weights = [1.0, 0.0, 5.0, 2.5, -1.0, 10.0, f64::NAN, 0.5];
+    let table = WeightedTable::new((0..weights.len()).collect(), &weights).unwrap();
+
+    let mut rng = RngComponent::with_seed(12345);
+    let mut table_counts = [0_usize; 8];
+    let mut naive_counts = [0_usize; 8];
+
+    for _ in 0..TRIALS {
+        table_counts[*table.sample(&mut rng)] += 1;
+        naive_counts[rng.weighted_index(&weights).unwrap()] += 1;
+    }
+
+    // Invalid weights are never sampled by either
+    for index in [1, 4, 6] {
+        assert_eq!(table_counts[index], 0);
+        assert_eq!(naive_counts[index], 0);
+    }
+
+    for (table_count, naive_count) in table_counts.iter().zip(naive_counts) {
+        let difference = (*table_count as f64 - naive_count as f64).abs() / TRIALS as f64;
+
+        assert!(
+            difference < 0.01,
+            "{:?} vs {:?}",
+            table_counts,
+            naive_counts
+        );
+    }
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn weighted_table_construction_errors() {
+    assert_eq!(
+        WeightedTable::new(vec![1, 2, 3], &[1.0, 2.0]).unwrap_err(),
+        WeightedTableError::LengthMismatch {
+            items: 3,
+            weights: 2
+        }
+    );
+    assert_eq!(
+        WeightedTable::<u32>::new(Vec::new(), &[]).unwrap_err(),
+        WeightedTableError::NoValidWeights
+    );
+    assert_eq!(
+        WeightedTable::new(vec![1, 2], &[0.0, f64::INFINITY]).unwrap_err(),
+        WeightedTableError::NoValidWeights
+    );
+
+    // Huge weights don't overflow the normalisation
+    let table = WeightedTable::new(vec!['a', 'b'], &[f64::MAX, f64::MAX]).unwrap();
+    let mut rng = GlobalRng::with_seed(12345);
+
+    assert_eq!(table.items(), &['a', 'b']);
+    assert!((0..100).any(|_| *table.sample(&mut rng) == 'a'));
+    assert!((0..100).any(|_| *table.sample(&mut rng) == 'b'));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_weighted_table() {
+    let table = WeightedTable::new(vec!["sword", "potion", "gold"], &[1.0, 10.0, 20.0]).unwrap();
+
+    let mut rng_1 = RngComponent::with_seed(23456);
+    let mut rng_2 = RngComponent::with_seed(23456);
+
+    let first: Vec<_> = (0..20).map(|_| *table.sample(&mut rng_1)).collect();
+    let second: Vec<_> = (0..20).map(|_| *table.sample(&mut rng_2)).collect();
+
+    assert_eq!(first, second);
+}
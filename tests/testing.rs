@@ -0,0 +1,48 @@
+#![cfg(feature = "testing")]
+
+use bevy_turborand::prelude::*;
+use bevy_turborand::testing::assert_deterministic;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn deterministic_closure_passes() {
+    assert_deterministic(12345, 10, |rng| {
+        let mut values: Vec<u32> = (0..20).map(|_| rng.u32(..)).collect();
+        rng.shuffle(&mut values);
+        values
+    });
+}
+
+#[test]
+#[should_panic(expected = "diverged from the first run at index 0")]
+fn non_deterministic_closure_panics() {
+    let mut offset = 0;
+
+    assert_deterministic(12345, 3, |rng| {
+        offset += 1;
+        vec![rng.u32(..) ^ offset]
+    });
+}
+
+#[test]
+#[should_panic(expected = "produced 2 values, but the first run produced 1")]
+fn differing_lengths_panic() {
+    let mut len = 0;
+
+    assert_deterministic(12345, 2, |rng| {
+        len += 1;
+        (0..len).map(|_| rng.u8(..)).collect()
+    });
+}
+
+#[test]
+#[should_panic(expected = "needs at least 2 runs")]
+fn too_few_runs_panics() {
+    assert_deterministic(12345, 1, |rng| vec![rng.u8(..)]);
+}